@@ -1,7 +1,7 @@
 #![no_std]
 
-use predictx_shared::{PredictXError, Poll, PollCategory, PollStatus};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
+use predictx_shared::{PredictXError, Poll, PollCategory, PollOutcome, PollStatus, MAX_QUESTION_LENGTH};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, String};
 
 #[contract]
 pub struct PollFactory;
@@ -58,6 +58,9 @@ impl PollFactory {
             return Err(PredictXError::NotInitialized);
         }
         creator.require_auth();
+        if question.len() > MAX_QUESTION_LENGTH {
+            return Err(PredictXError::PollQuestionTooLong);
+        }
 
         let poll_id = bump_poll_id(&env);
         let poll = Poll {
@@ -67,12 +70,11 @@ impl PollFactory {
 				question,
 				category: PollCategory::Other,
 				lock_time: lock_timestamp,
-				yes_pool: 0,
-				no_pool: 0,
-				yes_count: 0,
-				no_count: 0,
+				outcomes: vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")],
+				outcome_pools: vec![&env, 0, 0],
+				outcome_counts: vec![&env, 0, 0],
 				status: PollStatus::Active,
-				outcome: None,
+				outcome: PollOutcome::Pending,
 				resolution_time: 0,
 				created_at: env.ledger().timestamp(),
         };
@@ -15,6 +15,7 @@ fn workspace_root(manifest_dir: &Path) -> PathBuf {
 fn main() {
     println!("cargo:rerun-if-changed=../voting-oracle/Cargo.toml");
     println!("cargo:rerun-if-changed=../voting-oracle/src");
+    println!("cargo:rerun-if-changed=../../packages/shared/src");
 
     let manifest_dir =
         PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is required"));
@@ -0,0 +1,312 @@
+use soroban_sdk::{Address, Env, Symbol};
+use predictx_shared::{FeeSource, Poll, PollCategory, PredictXError};
+use crate::{matches, token_utils, DataKey};
+
+// ── Configuration ─────────────────────────────────────────────────────────────
+
+/// Bond a creator must post to open a poll of `category`; `0` until the admin
+/// sets one.
+pub fn get_creator_bond(env: &Env, category: PollCategory) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorBond(category))
+        .unwrap_or(0)
+}
+
+/// Set the creator bond for new polls of `category` (admin-only). Polls
+/// already open keep the bond they were created with.
+pub fn set_creator_bond(
+    env: &Env,
+    admin: Address,
+    category: PollCategory,
+    amount: i128,
+) -> Result<(), PredictXError> {
+    matches::require_admin(env, &admin)?;
+    if amount < 0 {
+        return Err(PredictXError::InvalidConfigAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CreatorBond(category), &amount);
+    env.events()
+        .publish((Symbol::new(env, "CreatorBondSet"), category), amount);
+    Ok(())
+}
+
+/// Bond still held for a poll; `0` once it has been returned or slashed.
+pub fn get_poll_bond(env: &Env, poll_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PollBond(poll_id))
+        .unwrap_or(0)
+}
+
+// ── Bond lifecycle ────────────────────────────────────────────────────────────
+
+/// Take the current bond for `category` from `creator` and hold it against
+/// a new poll.
+pub(crate) fn post(
+    env: &Env,
+    creator: &Address,
+    poll_id: u64,
+    category: PollCategory,
+) -> Result<(), PredictXError> {
+    let amount = get_creator_bond(env, category);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    token_utils::transfer_to_contract(env, creator, amount)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::PollBond(poll_id), &amount);
+    env.events().publish(
+        (Symbol::new(env, "BondPosted"), poll_id, creator.clone()),
+        amount,
+    );
+    Ok(())
+}
+
+/// Top up or partly refund a poll's bond so it matches the current bond for
+/// `category`, when its creator moves it there.
+pub(crate) fn rebond(env: &Env, poll: &Poll, category: PollCategory) -> Result<(), PredictXError> {
+    let held = get_poll_bond(env, poll.poll_id);
+    let required = get_creator_bond(env, category);
+    if required > held {
+        token_utils::transfer_to_contract(env, &poll.creator, required - held)?;
+    } else if held > required {
+        token_utils::transfer_from_contract(env, &poll.creator, held - required)?;
+    }
+
+    if required > 0 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PollBond(poll.poll_id), &required);
+    } else {
+        env.storage().persistent().remove(&DataKey::PollBond(poll.poll_id));
+    }
+    Ok(())
+}
+
+/// Hand a poll's bond back to its creator. A no-op if none is held.
+pub(crate) fn release(env: &Env, poll: &Poll) -> Result<(), PredictXError> {
+    let amount = take(env, poll.poll_id);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    token_utils::transfer_from_contract(env, &poll.creator, amount)?;
+    env.events().publish(
+        (Symbol::new(env, "BondReturned"), poll.poll_id, poll.creator.clone()),
+        amount,
+    );
+    Ok(())
+}
+
+/// Forfeit a poll's bond to the treasury. A no-op if none is held.
+pub(crate) fn slash(env: &Env, poll: &Poll) -> Result<(), PredictXError> {
+    let amount = take(env, poll.poll_id);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    token_utils::transfer_to_treasury(env, amount, FeeSource::SlashedBond)?;
+    env.events().publish(
+        (Symbol::new(env, "BondSlashed"), poll.poll_id, poll.creator.clone()),
+        amount,
+    );
+    Ok(())
+}
+
+fn take(env: &Env, poll_id: u64) -> i128 {
+    let amount = get_poll_bond(env, poll_id);
+    if amount > 0 {
+        env.storage().persistent().remove(&DataKey::PollBond(poll_id));
+    }
+    amount
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{testutils::Address as _, vec, Address, String};
+    use predictx_shared::{
+        FeeSource, PollCategory, PollOutcome, PollStatus, PredictXError, Score, StakeSide,
+    };
+    use crate::testutils::TestMarket;
+
+    const BOND: i128 = 50_000_000;
+    const LOCK_TIME: u64 = 2_000_000;
+
+    // ── Helpers ───────────────────────────────────────────────────────────────
+
+    /// Turn on the `PlayerEvent` bond and schedule a match to hang polls on.
+    fn bonded_match(m: &TestMarket) -> u64 {
+        m.client.set_creator_bond(&m.admin, &PollCategory::PlayerEvent, &BOND);
+        m.create_match(LOCK_TIME + 3600)
+    }
+
+    /// A funded creator opens a `PlayerEvent` poll. Returns (creator, poll_id).
+    fn create_bonded_poll(m: &TestMarket, match_id: u64) -> (Address, u64) {
+        let creator = Address::generate(&m.env);
+        m.mint(&creator, BOND);
+        let poll_id = m.create_poll(&creator, match_id, PollCategory::PlayerEvent, LOCK_TIME);
+        (creator, poll_id)
+    }
+
+    fn stake(m: &TestMarket, poll_id: u64, side: StakeSide) {
+        m.stake_as_new_user(poll_id, 100_000_000, side);
+    }
+
+    // ── Posting ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn create_poll_takes_the_category_bond() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let (creator, poll_id) = create_bonded_poll(&m, match_id);
+
+        assert_eq!(m.client.get_poll_bond(&poll_id), BOND);
+        assert_eq!(m.balance(&creator), 0);
+        assert_eq!(m.client.get_creator_bond(&PollCategory::TeamEvent), 0);
+    }
+
+    #[test]
+    fn create_poll_fails_without_funds_for_the_bond() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let result = m.client.try_create_poll(
+            &Address::generate(&m.env),
+            &match_id,
+            &String::from_str(&m.env, "Will Palmer score?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &LOCK_TIME,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn set_creator_bond_is_admin_only_and_validated() {
+        let m = TestMarket::new();
+        let err = m
+            .client
+            .try_set_creator_bond(&Address::generate(&m.env), &PollCategory::Other, &1)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::Unauthorized);
+
+        let err = m
+            .client
+            .try_set_creator_bond(&m.admin, &PollCategory::Other, &-1)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::InvalidConfigAmount);
+    }
+
+    // ── Return & slashing ─────────────────────────────────────────────────────
+
+    #[test]
+    fn bond_returned_on_clean_resolution() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let (creator, poll_id) = create_bonded_poll(&m, match_id);
+        stake(&m, poll_id, StakeSide::Yes);
+        stake(&m, poll_id, StakeSide::No);
+
+        m.set_time(LOCK_TIME + 1);
+        m.client.resolve_poll(&poll_id, &true);
+
+        assert_eq!(m.balance(&creator), BOND);
+        assert_eq!(m.client.get_poll_bond(&poll_id), 0);
+    }
+
+    #[test]
+    fn bond_returned_when_one_sided_poll_is_voided() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let (creator, poll_id) = create_bonded_poll(&m, match_id);
+        stake(&m, poll_id, StakeSide::Yes);
+
+        m.set_time(LOCK_TIME);
+        m.client.advance_poll(&poll_id);
+
+        assert_eq!(m.client.get_poll(&poll_id).outcome, PollOutcome::Void);
+        assert_eq!(m.balance(&creator), BOND);
+    }
+
+    #[test]
+    fn bond_slashed_when_voters_find_poll_unclear() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let (creator, poll_id) = create_bonded_poll(&m, match_id);
+        stake(&m, poll_id, StakeSide::Yes);
+        stake(&m, poll_id, StakeSide::No);
+
+        m.set_time(LOCK_TIME + 1);
+        m.client.void_poll(&poll_id, &true);
+
+        assert_eq!(m.balance(&creator), 0);
+        assert_eq!(m.treasury.fees_collected(&FeeSource::SlashedBond), BOND);
+        assert_eq!(m.client.get_poll_bond(&poll_id), 0);
+    }
+
+    #[test]
+    fn bond_returned_when_admin_voids_poll() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let creator = Address::generate(&m.env);
+        m.mint(&creator, BOND);
+        let poll_id = m.client.create_categorical_poll(
+            &creator,
+            &match_id,
+            &String::from_str(&m.env, "First scorer?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &vec![
+                &m.env,
+                String::from_str(&m.env, "Saka"),
+                String::from_str(&m.env, "Palmer"),
+                String::from_str(&m.env, "Nobody"),
+            ],
+            &LOCK_TIME,
+        );
+        for outcome in [0, 1] {
+            let user = Address::generate(&m.env);
+            m.mint(&user, 100_000_000);
+            m.client.stake_outcome(&user, &poll_id, &outcome, &100_000_000);
+        }
+
+        m.set_time(LOCK_TIME + 7_200);
+        m.client.finish_match(&m.admin, &match_id, &Score { home: 1, away: 0 }, &None);
+        assert_eq!(m.client.advance_poll(&poll_id), PollStatus::AdminReview);
+        m.oracle().admin_void(
+            &m.admin,
+            &poll_id,
+            &String::from_str(&m.env, "ipfs://QmDidNotStart"),
+        );
+
+        assert_eq!(m.client.get_poll(&poll_id).outcome, PollOutcome::Void);
+        assert_eq!(m.balance(&creator), BOND);
+        assert_eq!(m.treasury.fees_collected(&FeeSource::SlashedBond), 0);
+    }
+
+    #[test]
+    fn cancel_returns_bond_but_cancel_invalid_slashes_it() {
+        let m = TestMarket::with_oracle();
+        let match_id = bonded_match(&m);
+        let (fair, fair_poll) = create_bonded_poll(&m, match_id);
+        let (invalid, invalid_poll) = create_bonded_poll(&m, match_id);
+
+        m.client.cancel_poll(&m.admin, &fair_poll);
+        m.client.cancel_invalid_poll(&m.admin, &invalid_poll);
+
+        assert_eq!(m.balance(&fair), BOND);
+        assert_eq!(m.balance(&invalid), 0);
+        assert_eq!(m.treasury.fees_collected(&FeeSource::SlashedBond), BOND);
+    }
+}
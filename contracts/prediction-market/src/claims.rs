@@ -0,0 +1,638 @@
+use soroban_sdk::{Address, Env, Symbol};
+use predictx_shared::{
+    FeeSource, Poll, PollOutcome, PollStatus, PredictXError, BPS_DENOMINATOR, DISPUTE_WINDOW_SECS,
+};
+use crate::{DataKey, get_platform_stats, set_platform_stats, ensure_not_paused, resolution, staking, token_utils};
+
+// ── Winnings ──────────────────────────────────────────────────────────────────
+
+/// Pay out a winning stake on a resolved poll.
+///
+/// The payout uses the same pari-mutuel formula as
+/// `staking::calculate_potential_winnings`: the winner receives their share of
+/// the whole pool (their own stake plus a proportional slice of the losing
+/// pool) minus the platform and creator fees. The platform fee is routed to
+/// the treasury; the creator fee accrues to the poll's creator for
+/// `claim_creator_fees`. Any voter reward share carved out at resolution is
+/// excluded from the pool first.
+///
+/// On a `Void` poll, or one whose winning outcome nobody backed, every stake
+/// is returned in full with no fee.
+///
+/// Claims open once the dispute window after resolution has passed, and stay
+/// frozen while a dispute is open, so an overturned outcome never leaves the
+/// contract short of funds.
+///
+/// Returns the net amount transferred to `user`.
+pub fn claim_winnings(env: &Env, user: Address, poll_id: u64) -> Result<i128, PredictXError> {
+    user.require_auth();
+    ensure_not_paused(env)?;
+
+    // ── Checks ────────────────────────────────────────────────────────────────
+
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+
+    match poll.status {
+        PollStatus::Resolved => {}
+        PollStatus::Disputed => return Err(PredictXError::DisputeAlreadyOpen),
+        _ => return Err(PredictXError::PollNotResolved),
+    }
+    if poll.outcome == PollOutcome::Pending {
+        return Err(PredictXError::PollNotResolved);
+    }
+    if env.ledger().timestamp() < poll.resolution_time + DISPUTE_WINDOW_SECS {
+        return Err(PredictXError::DisputeWindowOpen);
+    }
+    let positions = staking::load_positions(env, poll_id, &user);
+    if positions.is_empty() {
+        return Err(PredictXError::NotStaker);
+    }
+
+    // Winners are paid on their winning position; when nobody is paid out,
+    // every position is refunded instead.
+    let winning = match resolution::paying_outcome(&poll) {
+        Some(outcome) => {
+            let stake = positions
+                .iter()
+                .find(|p| p.outcome == outcome)
+                .ok_or(PredictXError::NotOnWinningSide)?;
+            if stake.claimed {
+                return Err(PredictXError::AlreadyClaimed);
+            }
+            Some(stake)
+        }
+        None => None,
+    };
+
+    // ── Effects ───────────────────────────────────────────────────────────────
+
+    let is_win = winning.is_some();
+    let (gross, payout) = match winning {
+        Some(mut stake) => {
+            let winning_pool = poll.outcome_pools.get_unchecked(stake.outcome);
+            let total_pool =
+                staking::total_pool(&poll) - resolution::get_voter_reward_pool(env, poll_id);
+            let fee_bps = token_utils::get_payout_fee_bps(env);
+            let gross = stake.amount * total_pool / winning_pool;
+            let payout = staking::net_payout(stake.amount, winning_pool, total_pool, fee_bps);
+
+            stake.claimed = true;
+            staking::save_position(env, &stake);
+            (gross, payout)
+        }
+        None => {
+            let refund = staking::take_refund(env, poll_id, &user)?;
+            (refund, refund)
+        }
+    };
+    let fee = gross - payout;
+    let creator_fee = if is_win {
+        gross * token_utils::get_creator_fee_bps(env) as i128 / BPS_DENOMINATOR as i128
+    } else {
+        0
+    };
+    if creator_fee > 0 {
+        accrue_creator_fee(env, &poll, creator_fee);
+    }
+    let platform_fee = fee - creator_fee;
+
+    let mut stats = get_platform_stats(env);
+    stats.total_value_locked -= gross;
+    if is_win {
+        stats.total_payouts += payout;
+    }
+    set_platform_stats(env, &stats);
+
+    // ── Interactions ──────────────────────────────────────────────────────────
+
+    token_utils::transfer_from_contract(env, &user, payout)?;
+    if platform_fee > 0 {
+        token_utils::transfer_to_treasury(env, platform_fee, FeeSource::PlatformFee)?;
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "WinningsClaimed"), poll_id, user),
+        (payout, fee),
+    );
+
+    Ok(payout)
+}
+
+// ── Creator fees ──────────────────────────────────────────────────────────────
+
+/// Book `amount` of creator fee from `poll` to its creator, both as claimable
+/// and towards their lifetime earnings.
+fn accrue_creator_fee(env: &Env, poll: &Poll, amount: i128) {
+    let creator = &poll.creator;
+    env.storage().persistent().set(
+        &DataKey::CreatorFees(creator.clone()),
+        &(get_creator_fees(env, creator) + amount),
+    );
+    env.storage().persistent().set(
+        &DataKey::CreatorEarnings(creator.clone()),
+        &(get_creator_earnings(env, creator) + amount),
+    );
+    env.events().publish(
+        (Symbol::new(env, "CreatorFeeAccrued"), poll.poll_id, creator.clone()),
+        amount,
+    );
+}
+
+/// Pay out every creator fee `creator` has accrued across all of their polls.
+///
+/// Returns the amount transferred to `creator`.
+pub fn claim_creator_fees(env: &Env, creator: Address) -> Result<i128, PredictXError> {
+    creator.require_auth();
+    ensure_not_paused(env)?;
+
+    let amount = get_creator_fees(env, &creator);
+    if amount == 0 {
+        return Err(PredictXError::AlreadyClaimed);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CreatorFees(creator.clone()));
+
+    token_utils::transfer_from_contract(env, &creator, amount)?;
+
+    env.events().publish(
+        (Symbol::new(env, "CreatorFeesClaimed"), creator),
+        amount,
+    );
+    Ok(amount)
+}
+
+/// Creator fees accrued to `creator` and not yet claimed.
+pub fn get_creator_fees(env: &Env, creator: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorFees(creator.clone()))
+        .unwrap_or(0)
+}
+
+/// Every creator fee ever accrued to `creator`, claimed or not.
+pub fn get_creator_earnings(env: &Env, creator: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorEarnings(creator.clone()))
+        .unwrap_or(0)
+}
+
+// ── Refunds ───────────────────────────────────────────────────────────────────
+
+/// Return every position a user holds on a cancelled poll in full. No fee is
+/// taken.
+///
+/// Returns the amount transferred back to `user`.
+pub fn claim_refund(env: &Env, user: Address, poll_id: u64) -> Result<i128, PredictXError> {
+    user.require_auth();
+    ensure_not_paused(env)?;
+
+    // ── Checks ────────────────────────────────────────────────────────────────
+
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    if poll.status != PollStatus::Cancelled {
+        return Err(PredictXError::PollNotCancelled);
+    }
+
+    // ── Effects ───────────────────────────────────────────────────────────────
+
+    let refund = staking::take_refund(env, poll_id, &user)?;
+
+    let mut stats = get_platform_stats(env);
+    stats.total_value_locked -= refund;
+    set_platform_stats(env, &stats);
+
+    // ── Interactions ──────────────────────────────────────────────────────────
+
+    token_utils::transfer_from_contract(env, &user, refund)?;
+
+    env.events().publish(
+        (Symbol::new(env, "RefundClaimed"), poll_id, user),
+        refund,
+    );
+
+    Ok(refund)
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{testutils::Address as _, Address, String};
+    use predictx_shared::{
+        FeeSource, PollCategory, PollOutcome, PollStatus, PredictXError, StakeSide,
+        DISPUTE_WINDOW_SECS,
+    };
+    use crate::testutils::TestMarket;
+
+    // ── Helpers ───────────────────────────────────────────────────────────────
+
+    fn create_test_poll(s: &TestMarket) -> u64 {
+        let match_id = s.create_match(2_003_600);
+        s.create_poll(&s.admin, match_id, PollCategory::PlayerEvent, 2_000_000)
+    }
+
+    /// Move past lock time, resolve through the oracle entrypoint, then let
+    /// the dispute window lapse.
+    fn resolve(s: &TestMarket, poll_id: u64, outcome: bool) {
+        s.set_time(2_000_001);
+        s.client.resolve_poll(&poll_id, &outcome);
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+    }
+
+    // ── Payout maths ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn winner_receives_proportional_share_minus_fee() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let yes1 = s.stake_as_new_user(poll_id, 600_000_000, StakeSide::Yes);
+        let yes2 = s.stake_as_new_user(poll_id, 400_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 1_000_000_000, StakeSide::No);
+        resolve(&s, poll_id, true);
+
+        // total = 2_000_000_000, yes_pool = 1_000_000_000
+        // yes1 gross = 1_200_000_000, fee 5% = 60_000_000
+        let payout1 = s.client.claim_winnings(&yes1, &poll_id);
+        assert_eq!(payout1, 1_140_000_000);
+        assert_eq!(s.balance(&yes1), 1_140_000_000);
+
+        let payout2 = s.client.claim_winnings(&yes2, &poll_id);
+        assert_eq!(payout2, 760_000_000);
+
+        assert_eq!(s.balance(&s.treasury.address), 100_000_000);
+        assert_eq!(s.treasury.fees_collected(&FeeSource::PlatformFee), 100_000_000);
+        assert_eq!(s.balance(&s.contract_id), 0);
+    }
+
+    #[test]
+    fn creator_fee_accrues_to_creator_until_claimed() {
+        let s = TestMarket::new();
+        s.client.set_creator_fee_bps(&s.admin, &200);
+        let poll_id = create_test_poll(&s);
+        let yes1 = s.stake_as_new_user(poll_id, 600_000_000, StakeSide::Yes);
+        let yes2 = s.stake_as_new_user(poll_id, 400_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 1_000_000_000, StakeSide::No);
+        resolve(&s, poll_id, true);
+
+        // yes1 gross = 1_200_000_000: 5% platform fee + 2% creator fee
+        assert_eq!(s.client.claim_winnings(&yes1, &poll_id), 1_116_000_000);
+        assert_eq!(s.client.claim_winnings(&yes2, &poll_id), 744_000_000);
+
+        assert_eq!(s.treasury.fees_collected(&FeeSource::PlatformFee), 100_000_000);
+        assert_eq!(s.client.get_creator_fees(&s.admin), 40_000_000);
+
+        assert_eq!(s.client.claim_creator_fees(&s.admin), 40_000_000);
+        assert_eq!(s.balance(&s.admin), 40_000_000);
+        assert_eq!(s.balance(&s.contract_id), 0);
+        assert_eq!(s.client.get_creator_fees(&s.admin), 0);
+        assert_eq!(s.client.get_creator_earnings(&s.admin), 40_000_000);
+
+        let err = s.client.try_claim_creator_fees(&s.admin).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn creator_fee_cannot_push_total_fee_over_everything() {
+        let s = TestMarket::new();
+        let err = s.client.try_set_creator_fee_bps(&s.admin, &9_501).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidFeeBps);
+        assert_eq!(s.client.get_creator_fee_bps(), 0);
+    }
+
+    #[test]
+    fn payout_matches_potential_winnings_preview() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        s.stake_as_new_user(poll_id, 700_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 300_000_000, StakeSide::No);
+
+        let quoted = s.client.calculate_potential_winnings(&poll_id, &StakeSide::No, &200_000_000);
+        let user = s.stake_as_new_user(poll_id, 200_000_000, StakeSide::No);
+        resolve(&s, poll_id, false);
+
+        assert_eq!(s.client.claim_winnings(&user, &poll_id), quoted);
+    }
+
+    #[test]
+    fn claim_marks_stake_and_updates_stats() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let winner = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::No);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        resolve(&s, poll_id, false);
+
+        let payout = s.client.claim_winnings(&winner, &poll_id);
+
+        assert!(s.client.get_stake_info(&poll_id, &winner).claimed);
+        let stats = s.client.get_platform_stats();
+        assert_eq!(stats.total_payouts, payout);
+        assert_eq!(stats.total_value_locked, 0);
+    }
+
+    #[test]
+    fn voter_reward_share_is_carved_before_payouts() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let winner = s.stake_as_new_user(poll_id, 600_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 400_000_000, StakeSide::No);
+        s.set_time(2_000_001);
+        s.client.resolve_poll(&poll_id, &true);
+
+        // 1% of the 1_000_000_000 pool goes to the oracle, once.
+        assert_eq!(s.client.fund_voter_rewards(&poll_id), 10_000_000);
+        assert_eq!(s.client.fund_voter_rewards(&poll_id), 10_000_000);
+        assert_eq!(s.balance(&s.oracle_id), 10_000_000);
+
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+        // gross = 990_000_000, fee 5% = 49_500_000
+        assert_eq!(s.client.claim_winnings(&winner, &poll_id), 940_500_000);
+        assert_eq!(s.balance(&s.contract_id), 0);
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 0);
+    }
+
+    #[test]
+    fn categorical_winner_takes_the_whole_pool() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(2_003_600);
+        let poll_id = s.client.create_categorical_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "Full-time result?"),
+            &PollCategory::TeamEvent,
+            &None,
+            &soroban_sdk::vec![
+                &s.env,
+                String::from_str(&s.env, "Arsenal"),
+                String::from_str(&s.env, "Draw"),
+                String::from_str(&s.env, "Chelsea"),
+            ],
+            &2_000_000,
+        );
+
+        let mut users = std::vec::Vec::new();
+        for (outcome, amount) in [(0_u32, 100_000_000_i128), (1, 200_000_000), (2, 300_000_000)] {
+            let user = Address::generate(&s.env);
+            s.mint(&user, amount);
+            s.client.stake_outcome(&user, &poll_id, &outcome, &amount);
+            users.push(user);
+        }
+        s.set_time(2_000_001);
+        s.client.resolve_poll_outcome(&poll_id, &2);
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+
+        // total = 600_000_000, fee 5% = 30_000_000
+        assert_eq!(s.client.claim_winnings(&users[2], &poll_id), 570_000_000);
+        let err = s.client.try_claim_winnings(&users[1], &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::NotOnWinningSide);
+        assert_eq!(s.balance(&s.treasury.address), 30_000_000);
+    }
+
+    // ── Refunds ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn refund_returns_full_stake_after_match_cancelled() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let yes = s.stake_as_new_user(poll_id, 300_000_000, StakeSide::Yes);
+        let no = s.stake_as_new_user(poll_id, 200_000_000, StakeSide::No);
+        let match_id = s.client.get_poll(&poll_id).match_id;
+        s.client.cancel_match(&s.admin, &match_id, &String::from_str(&s.env, "Postponed"));
+
+        assert_eq!(s.client.claim_refund(&yes, &poll_id), 300_000_000);
+        assert_eq!(s.client.claim_refund(&no, &poll_id), 200_000_000);
+        assert_eq!(s.balance(&yes), 300_000_000);
+        assert_eq!(s.balance(&s.contract_id), 0);
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 0);
+
+        let err = s.client.try_claim_refund(&yes, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn void_poll_refunds_every_stake_without_fee() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let yes = s.stake_as_new_user(poll_id, 300_000_000, StakeSide::Yes);
+        let no = s.stake_as_new_user(poll_id, 200_000_000, StakeSide::No);
+        s.set_time(2_000_001);
+        s.client.void_poll(&poll_id, &false);
+
+        // A void can be disputed like any other outcome.
+        let err = s.client.try_claim_winnings(&yes, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::DisputeWindowOpen);
+
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+        assert_eq!(s.client.claim_winnings(&yes, &poll_id), 300_000_000);
+        assert_eq!(s.client.claim_winnings(&no, &poll_id), 200_000_000);
+        assert_eq!(s.balance(&no), 200_000_000);
+        assert_eq!(s.balance(&s.treasury.address), 0);
+        assert_eq!(s.balance(&s.contract_id), 0);
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 0);
+
+        let err = s.client.try_claim_winnings(&yes, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn one_sided_poll_is_voided_at_lock() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let yes1 = s.stake_as_new_user(poll_id, 300_000_000, StakeSide::Yes);
+        let yes2 = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        s.set_time(2_000_000);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Resolved);
+        assert_eq!(s.client.get_poll(&poll_id).outcome, PollOutcome::Void);
+        s.set_time(2_000_000 + DISPUTE_WINDOW_SECS);
+        assert_eq!(s.client.claim_winnings(&yes1, &poll_id), 300_000_000);
+        assert_eq!(s.client.claim_winnings(&yes2, &poll_id), 100_000_000);
+        assert_eq!(s.balance(&s.treasury.address), 0);
+    }
+
+    #[test]
+    fn one_sided_poll_resolved_by_oracle_is_voided() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let no = s.stake_as_new_user(poll_id, 200_000_000, StakeSide::No);
+        s.set_time(2_000_001);
+        s.client.resolve_poll(&poll_id, &false);
+
+        assert_eq!(s.client.get_poll(&poll_id).outcome, PollOutcome::Void);
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+        assert_eq!(s.client.claim_winnings(&no, &poll_id), 200_000_000);
+        assert_eq!(s.balance(&s.treasury.address), 0);
+    }
+
+    #[test]
+    fn unbacked_winning_outcome_refunds_everyone() {
+        let s = TestMarket::new();
+        let match_id = s.client.get_poll(&create_test_poll(&s)).match_id;
+        let poll_id = s.client.create_categorical_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "Full-time result?"),
+            &PollCategory::TeamEvent,
+            &None,
+            &soroban_sdk::vec![
+                &s.env,
+                String::from_str(&s.env, "Arsenal"),
+                String::from_str(&s.env, "Draw"),
+                String::from_str(&s.env, "Chelsea"),
+            ],
+            &2_000_000,
+        );
+        let mut users = std::vec::Vec::new();
+        for (outcome, amount) in [(0_u32, 100_000_000_i128), (1, 200_000_000)] {
+            let user = Address::generate(&s.env);
+            s.mint(&user, amount);
+            s.client.stake_outcome(&user, &poll_id, &outcome, &amount);
+            users.push(user);
+        }
+        s.set_time(2_000_001);
+        s.client.resolve_poll_outcome(&poll_id, &2);
+        assert_eq!(s.client.fund_voter_rewards(&poll_id), 0);
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+
+        assert_eq!(s.client.claim_winnings(&users[0], &poll_id), 100_000_000);
+        assert_eq!(s.client.claim_winnings(&users[1], &poll_id), 200_000_000);
+        assert_eq!(s.balance(&s.treasury.address), 0);
+        assert_eq!(s.balance(&s.contract_id), 0);
+    }
+
+    #[test]
+    fn hedger_is_paid_on_winning_position_only() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        s.client.set_poll_hedging(&s.admin, &poll_id, &true);
+        let hedger = s.stake_as_new_user(poll_id, 300_000_000, StakeSide::Yes);
+        s.mint(&hedger, 100_000_000);
+        s.client.stake(&hedger, &poll_id, &100_000_000, &StakeSide::No);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::No);
+        resolve(&s, poll_id, false);
+
+        // total = 500_000_000, no pool = 200_000_000
+        // gross = 250_000_000, fee 5% = 12_500_000
+        assert_eq!(s.client.claim_winnings(&hedger, &poll_id), 237_500_000);
+        let err = s.client.try_claim_winnings(&hedger, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::AlreadyClaimed);
+        assert!(!s.client.get_stake_info(&poll_id, &hedger).claimed);
+    }
+
+    #[test]
+    fn refund_returns_every_hedged_position() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        s.client.set_poll_hedging(&s.admin, &poll_id, &true);
+        let hedger = s.stake_as_new_user(poll_id, 300_000_000, StakeSide::Yes);
+        s.mint(&hedger, 100_000_000);
+        s.client.stake(&hedger, &poll_id, &100_000_000, &StakeSide::No);
+        let match_id = s.client.get_poll(&poll_id).match_id;
+        s.client.cancel_match(&s.admin, &match_id, &String::from_str(&s.env, "Postponed"));
+
+        assert_eq!(s.client.claim_refund(&hedger, &poll_id), 400_000_000);
+        assert_eq!(s.balance(&hedger), 400_000_000);
+        assert_eq!(s.balance(&s.contract_id), 0);
+    }
+
+    #[test]
+    fn refund_rejects_live_poll() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let user = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+
+        let err = s.client.try_claim_refund(&user, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotCancelled);
+    }
+
+    // ── Rejections ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn claim_rejects_unresolved_poll() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let user = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+
+        let err = s.client.try_claim_winnings(&user, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotResolved);
+    }
+
+    #[test]
+    fn claim_rejects_during_dispute_window() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let winner = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::No);
+        s.set_time(2_000_001);
+        s.client.resolve_poll(&poll_id, &true);
+
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS - 1);
+        let err = s.client.try_claim_winnings(&winner, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::DisputeWindowOpen);
+    }
+
+    #[test]
+    fn claim_rejects_disputed_poll() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let winner = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        s.set_time(2_000_001);
+        s.client.resolve_poll(&poll_id, &true);
+        s.client.mark_disputed(&poll_id);
+
+        s.set_time(2_000_001 + DISPUTE_WINDOW_SECS);
+        let err = s.client.try_claim_winnings(&winner, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::DisputeAlreadyOpen);
+    }
+
+    #[test]
+    fn claim_rejects_losing_side() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        let loser = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::No);
+        resolve(&s, poll_id, true);
+
+        let err = s.client.try_claim_winnings(&loser, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::NotOnWinningSide);
+    }
+
+    #[test]
+    fn claim_rejects_double_claim() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        let winner = s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::No);
+        resolve(&s, poll_id, true);
+
+        s.client.claim_winnings(&winner, &poll_id);
+        let err = s.client.try_claim_winnings(&winner, &poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn claim_rejects_non_staker() {
+        let s = TestMarket::new();
+        let poll_id = create_test_poll(&s);
+        s.stake_as_new_user(poll_id, 100_000_000, StakeSide::Yes);
+        resolve(&s, poll_id, true);
+
+        let err = s
+            .client
+            .try_claim_winnings(&Address::generate(&s.env), &poll_id)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::NotStaker);
+    }
+}
@@ -0,0 +1,399 @@
+use core::cmp::Ordering;
+use soroban_sdk::{Env, Vec};
+use predictx_shared::{
+    Match, MatchEvent, MatchEventKind, Poll, PollCategory, PollCondition, PollOutcome, PollStatus,
+    PredictXError, Score, Team,
+};
+use crate::{matches, resolution, DataKey};
+
+// ── Condition evaluation ──────────────────────────────────────────────────────
+
+/// Whether a poll of `category` may carry `condition`. `Other` polls are
+/// free-form and never take one.
+pub(crate) fn fits_category(condition: &PollCondition, category: PollCategory) -> bool {
+    match condition {
+        PollCondition::TotalGoalsOver(_)
+        | PollCondition::TotalGoalsUnder(_)
+        | PollCondition::BothTeamsScore
+        | PollCondition::ExactScore(_) => category == PollCategory::ScorePrediction,
+        PollCondition::TeamWins(_) | PollCondition::MatchResult => {
+            matches!(category, PollCategory::TeamEvent | PollCategory::ScorePrediction)
+        }
+        PollCondition::CleanSheet(_) | PollCondition::TeamRedCard(_) => {
+            category == PollCategory::TeamEvent
+        }
+        PollCondition::PlayerScores(_) | PollCondition::PlayerBooked(_) => {
+            category == PollCategory::PlayerEvent
+        }
+    }
+}
+
+/// Number of outcomes a poll carrying `condition` must have.
+pub(crate) fn outcome_count(condition: &PollCondition) -> u32 {
+    match condition {
+        PollCondition::MatchResult => 3,
+        _ => 2,
+    }
+}
+
+/// Index of the outcome `condition` settles on for a finished match, or `None`
+/// if it cannot be decided on-chain. Score conditions need the final score;
+/// player and card conditions also need an event log whose goals add up to
+/// that score, so a missing or partial feed leaves the poll to the community
+/// vote.
+pub(crate) fn evaluate(
+    condition: &PollCondition,
+    m: &Match,
+    events: &Vec<MatchEvent>,
+) -> Option<u32> {
+    let score = Score { home: m.home_score?, away: m.away_score? };
+    if *condition == PollCondition::MatchResult {
+        return Some(match score.home.cmp(&score.away) {
+            Ordering::Greater => 0,
+            Ordering::Equal => 1,
+            Ordering::Less => 2,
+        });
+    }
+    holds(condition, &score, events).map(resolution::binary_index)
+}
+
+/// Whether a Yes/No `condition` held, or `None` if it cannot be decided.
+fn holds(condition: &PollCondition, score: &Score, events: &Vec<MatchEvent>) -> Option<bool> {
+    let total = score.home + score.away;
+    Some(match condition {
+        PollCondition::TotalGoalsOver(line) => total > *line,
+        PollCondition::TotalGoalsUnder(line) => total <= *line,
+        PollCondition::TeamWins(Team::Home) => score.home > score.away,
+        PollCondition::TeamWins(Team::Away) => score.away > score.home,
+        PollCondition::BothTeamsScore => score.home > 0 && score.away > 0,
+        PollCondition::ExactScore(exact) => exact == score,
+        PollCondition::CleanSheet(Team::Home) => score.away == 0,
+        PollCondition::CleanSheet(Team::Away) => score.home == 0,
+        PollCondition::PlayerScores(player_id) => {
+            if !log_covers(events, score) {
+                return None;
+            }
+            events.iter().any(|e| {
+                e.player_id == *player_id
+                    && matches!(e.kind, MatchEventKind::Goal | MatchEventKind::Penalty)
+            })
+        }
+        PollCondition::PlayerBooked(player_id) => {
+            if !log_covers(events, score) {
+                return None;
+            }
+            events.iter().any(|e| {
+                e.player_id == *player_id
+                    && matches!(e.kind, MatchEventKind::YellowCard | MatchEventKind::RedCard)
+            })
+        }
+        PollCondition::TeamRedCard(team) => {
+            if !log_covers(events, score) {
+                return None;
+            }
+            events
+                .iter()
+                .any(|e| e.team == *team && e.kind == MatchEventKind::RedCard)
+        }
+        PollCondition::MatchResult => return None,
+    })
+}
+
+/// Whether an event log was kept for the match and its goals add up to `score`.
+fn log_covers(events: &Vec<MatchEvent>, score: &Score) -> bool {
+    !events.is_empty() && logged_score(events) == *score
+}
+
+/// Score implied by the goals in an event log.
+fn logged_score(events: &Vec<MatchEvent>) -> Score {
+    let mut score = Score { home: 0, away: 0 };
+    for e in events.iter() {
+        let scoring_team = match (e.kind, e.team) {
+            (MatchEventKind::Goal | MatchEventKind::Penalty, team) => team,
+            (MatchEventKind::OwnGoal, Team::Home) => Team::Away,
+            (MatchEventKind::OwnGoal, Team::Away) => Team::Home,
+            _ => continue,
+        };
+        match scoring_team {
+            Team::Home => score.home += 1,
+            Team::Away => score.away += 1,
+        }
+    }
+    score
+}
+
+pub fn get_poll_condition(env: &Env, poll_id: u64) -> Option<PollCondition> {
+    env.storage().persistent().get(&DataKey::PollCondition(poll_id))
+}
+
+/// Resolve every open poll of `m` whose condition can be decided from its
+/// result and event log. Other polls are left for the community vote.
+pub(crate) fn resolve_match_polls(env: &Env, m: &Match) -> Result<(), PredictXError> {
+    let events = matches::get_match_events(env, m.match_id)?;
+    for poll_id in matches::get_match_polls(env, m.match_id)?.iter() {
+        let mut poll: Poll = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Poll(poll_id))
+            .ok_or(PredictXError::PollNotFound)?;
+        if !matches!(poll.status, PollStatus::Active | PollStatus::Locked) {
+            continue;
+        }
+        let Some(outcome) = get_poll_condition(env, poll_id).and_then(|c| evaluate(&c, m, &events)) else {
+            continue;
+        };
+
+        resolution::apply_resolution(env, &mut poll, PollOutcome::Winner(outcome), false)?;
+        env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+    }
+    Ok(())
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{vec, String};
+    use predictx_shared::{
+        MatchEvent, MatchEventKind, PollCategory, PollCondition, PollOutcome, PollStatus,
+        PredictXError, Score, Team,
+    };
+    use crate::testutils::TestMarket;
+
+    const KICKOFF: u64 = 2_000_000;
+
+    fn poll(s: &TestMarket, match_id: u64, condition: Option<PollCondition>) -> u64 {
+        poll_in(s, match_id, PollCategory::ScorePrediction, condition)
+    }
+
+    fn poll_in(s: &TestMarket, match_id: u64, category: PollCategory, condition: Option<PollCondition>) -> u64 {
+        s.client.create_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "Prediction"),
+            &category,
+            &condition,
+            &(KICKOFF - 600),
+        )
+    }
+
+    fn kick_off(s: &TestMarket, match_id: u64) {
+        s.set_time(KICKOFF);
+        s.client.start_match(&s.admin, &match_id);
+    }
+
+    fn log(s: &TestMarket, match_id: u64, minute: u32, kind: MatchEventKind, team: Team, player_id: u32) {
+        s.client.record_match_event(
+            &s.admin,
+            &match_id,
+            &MatchEvent { minute, kind, team, player_id },
+        );
+    }
+
+    fn finish(s: &TestMarket, match_id: u64, home: u32, away: u32) {
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &Score { home, away }, &None);
+    }
+
+    /// Resolved outcome of a Yes/No poll: `Some(true)` if Yes won.
+    fn outcome(s: &TestMarket, poll_id: u64) -> Option<bool> {
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        match poll.outcome {
+            PollOutcome::Winner(index) => Some(index == 0),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn finish_match_resolves_goal_lines() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let over_2_5 = poll(&s, match_id, Some(PollCondition::TotalGoalsOver(2)));
+        let over_3_5 = poll(&s, match_id, Some(PollCondition::TotalGoalsOver(3)));
+        let under_3_5 = poll(&s, match_id, Some(PollCondition::TotalGoalsUnder(3)));
+        assert_eq!(s.client.get_poll_condition(&over_2_5), Some(PollCondition::TotalGoalsOver(2)));
+        finish(&s, match_id, 2, 1);
+
+        assert_eq!(outcome(&s, over_2_5), Some(true));
+        assert_eq!(outcome(&s, over_3_5), Some(false));
+        assert_eq!(outcome(&s, under_3_5), Some(true));
+    }
+
+    #[test]
+    fn finish_match_resolves_result_conditions() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let home = poll(&s, match_id, Some(PollCondition::TeamWins(Team::Home)));
+        let away = poll(&s, match_id, Some(PollCondition::TeamWins(Team::Away)));
+        let btts = poll(&s, match_id, Some(PollCondition::BothTeamsScore));
+        let exact = poll(&s, match_id, Some(PollCondition::ExactScore(Score { home: 0, away: 2 })));
+        finish(&s, match_id, 0, 2);
+
+        assert_eq!(outcome(&s, home), Some(false));
+        assert_eq!(outcome(&s, away), Some(true));
+        assert_eq!(outcome(&s, btts), Some(false));
+        assert_eq!(outcome(&s, exact), Some(true));
+    }
+
+    #[test]
+    fn draw_is_a_loss_for_both_teams() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let home = poll(&s, match_id, Some(PollCondition::TeamWins(Team::Home)));
+        let away = poll(&s, match_id, Some(PollCondition::TeamWins(Team::Away)));
+        finish(&s, match_id, 1, 1);
+
+        assert_eq!(outcome(&s, home), Some(false));
+        assert_eq!(outcome(&s, away), Some(false));
+    }
+
+    #[test]
+    fn poll_without_condition_is_left_for_voting() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = poll(&s, match_id, None);
+        finish(&s, match_id, 3, 0);
+
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Active);
+        assert_eq!(poll.outcome, PollOutcome::Pending);
+        assert_eq!(s.client.get_poll_condition(&poll_id), None);
+    }
+
+    #[test]
+    fn finish_match_resolves_team_conditions() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let wins = poll_in(&s, match_id, PollCategory::TeamEvent, Some(PollCondition::TeamWins(Team::Home)));
+        let home_cs = poll_in(&s, match_id, PollCategory::TeamEvent, Some(PollCondition::CleanSheet(Team::Home)));
+        let away_cs = poll_in(&s, match_id, PollCategory::TeamEvent, Some(PollCondition::CleanSheet(Team::Away)));
+        finish(&s, match_id, 1, 0);
+
+        assert_eq!(outcome(&s, wins), Some(true));
+        assert_eq!(outcome(&s, home_cs), Some(true));
+        assert_eq!(outcome(&s, away_cs), Some(false));
+    }
+
+    #[test]
+    fn player_condition_is_not_settled_by_score() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = poll_in(&s, match_id, PollCategory::PlayerEvent, Some(PollCondition::PlayerScores(10)));
+        finish(&s, match_id, 1, 0);
+
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::Active);
+    }
+
+    #[test]
+    fn finish_match_resolves_player_conditions_from_event_log() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let palmer_scores = poll_in(&s, match_id, PollCategory::PlayerEvent, Some(PollCondition::PlayerScores(20)));
+        let saka_scores = poll_in(&s, match_id, PollCategory::PlayerEvent, Some(PollCondition::PlayerScores(7)));
+        let rice_booked = poll_in(&s, match_id, PollCategory::PlayerEvent, Some(PollCondition::PlayerBooked(41)));
+        let home_red = poll_in(&s, match_id, PollCategory::TeamEvent, Some(PollCondition::TeamRedCard(Team::Home)));
+        let away_red = poll_in(&s, match_id, PollCategory::TeamEvent, Some(PollCondition::TeamRedCard(Team::Away)));
+
+        kick_off(&s, match_id);
+        log(&s, match_id, 12, MatchEventKind::Penalty, Team::Away, 20);
+        log(&s, match_id, 30, MatchEventKind::YellowCard, Team::Home, 41);
+        // Saka's own goal counts for Chelsea, not as a Saka goal.
+        log(&s, match_id, 55, MatchEventKind::OwnGoal, Team::Home, 7);
+        log(&s, match_id, 80, MatchEventKind::RedCard, Team::Home, 41);
+        finish(&s, match_id, 0, 2);
+
+        assert_eq!(outcome(&s, palmer_scores), Some(true));
+        assert_eq!(outcome(&s, saka_scores), Some(false));
+        assert_eq!(outcome(&s, rice_booked), Some(true));
+        assert_eq!(outcome(&s, home_red), Some(true));
+        assert_eq!(outcome(&s, away_red), Some(false));
+    }
+
+    #[test]
+    fn incomplete_event_log_leaves_player_poll_to_voting() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = poll_in(&s, match_id, PollCategory::PlayerEvent, Some(PollCondition::PlayerScores(20)));
+
+        kick_off(&s, match_id);
+        log(&s, match_id, 12, MatchEventKind::Goal, Team::Home, 9);
+        finish(&s, match_id, 2, 0);
+
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::Active);
+    }
+
+    #[test]
+    fn condition_must_match_category() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let mismatched = [
+            (PollCategory::TeamEvent, PollCondition::TotalGoalsOver(2)),
+            (PollCategory::PlayerEvent, PollCondition::TeamWins(Team::Home)),
+            (PollCategory::ScorePrediction, PollCondition::PlayerScores(10)),
+            (PollCategory::ScorePrediction, PollCondition::CleanSheet(Team::Away)),
+            (PollCategory::Other, PollCondition::BothTeamsScore),
+        ];
+        for (category, condition) in mismatched {
+            let err = s
+                .client
+                .try_create_poll(
+                    &s.admin,
+                    &match_id,
+                    &String::from_str(&s.env, "Prediction"),
+                    &category,
+                    &Some(condition),
+                    &(KICKOFF - 600),
+                )
+                .unwrap_err()
+                .unwrap();
+            assert_eq!(err, PredictXError::InvalidPollCategory);
+        }
+    }
+
+    #[test]
+    fn finish_match_resolves_match_result_poll() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = s.client.create_categorical_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "Full-time result?"),
+            &PollCategory::TeamEvent,
+            &Some(PollCondition::MatchResult),
+            &vec![
+                &s.env,
+                String::from_str(&s.env, "Chelsea"),
+                String::from_str(&s.env, "Draw"),
+                String::from_str(&s.env, "Arsenal"),
+            ],
+            &(KICKOFF - 600),
+        );
+        finish(&s, match_id, 1, 1);
+
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(1));
+    }
+
+    #[test]
+    fn match_result_needs_three_outcomes() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let err = s
+            .client
+            .try_create_poll(
+                &s.admin,
+                &match_id,
+                &String::from_str(&s.env, "Full-time result?"),
+                &PollCategory::TeamEvent,
+                &Some(PollCondition::MatchResult),
+                &(KICKOFF - 600),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::InvalidOutcome);
+    }
+}
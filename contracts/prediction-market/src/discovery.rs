@@ -0,0 +1,317 @@
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+use predictx_shared::{
+    MatchStatus, Poll, PollCategory, PollStatus, PredictXError, MAX_PAGE_SCAN, MAX_PAGE_SIZE,
+};
+use crate::{matches, DataKey, MatchPage, MatchSummary, PollPage};
+
+// ── Listing views ─────────────────────────────────────────────────────────────
+//
+// `list_matches` and `list_polls` walk ids oldest first from `cursor`, keeping
+// those that pass their filters. Each call stops after `limit` entries (capped
+// at `MAX_PAGE_SIZE`) or `MAX_PAGE_SCAN` ids, whichever comes first, and hands
+// back the id to resume from.
+
+/// Page through matches, optionally only those in `status`.
+pub fn list_matches(
+    env: &Env,
+    cursor: u64,
+    limit: u32,
+    status: Option<MatchStatus>,
+) -> MatchPage {
+    let end = matches::get_match_count(env) + 1;
+    let (matches, next_cursor) = scan(env, cursor, end, limit, |match_id| {
+        matches::get_match(env, match_id)
+            .ok()
+            .filter(|m| status.is_none_or(|s| s == m.status))
+    });
+    MatchPage { matches, next_cursor }
+}
+
+/// Page through polls, optionally only those in `status` and/or `category`.
+/// Deleted polls are skipped.
+pub fn list_polls(
+    env: &Env,
+    cursor: u64,
+    limit: u32,
+    status: Option<PollStatus>,
+    category: Option<PollCategory>,
+) -> PollPage {
+    let end: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextPollId)
+        .unwrap_or(1);
+    let (polls, next_cursor) = scan(env, cursor, end, limit, |poll_id| {
+        env.storage()
+            .persistent()
+            .get::<_, Poll>(&DataKey::Poll(poll_id))
+            .filter(|poll| {
+                status.is_none_or(|s| s == poll.status)
+                    && category.is_none_or(|c| c == poll.category)
+            })
+    });
+    PollPage { polls, next_cursor }
+}
+
+/// Page through the polls `creator` has created and not deleted, skipping the
+/// first `offset`.
+pub fn list_polls_by_creator(env: &Env, creator: &Address, offset: u32, limit: u32) -> Vec<Poll> {
+    let mut page = Vec::new(env);
+    for poll_id in get_creator_polls(env, creator).iter().skip(offset as usize) {
+        if page.len() >= limit.min(MAX_PAGE_SIZE) {
+            break;
+        }
+        if let Some(poll) = env.storage().persistent().get(&DataKey::Poll(poll_id)) {
+            page.push_back(poll);
+        }
+    }
+    page
+}
+
+/// A match with every poll on it, pools and status included, in one call.
+pub fn get_match_summary(env: &Env, match_id: u64) -> Result<MatchSummary, PredictXError> {
+    let details = matches::get_match(env, match_id)?;
+    let mut polls = Vec::new(env);
+    for poll_id in matches::get_match_polls(env, match_id)?.iter() {
+        if let Some(poll) = env.storage().persistent().get(&DataKey::Poll(poll_id)) {
+            polls.push_back(poll);
+        }
+    }
+    Ok(MatchSummary { details, polls })
+}
+
+// ── Creator index ─────────────────────────────────────────────────────────────
+
+/// Ids of every poll `creator` has created and not deleted, oldest first.
+pub(crate) fn get_creator_polls(env: &Env, creator: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorPolls(creator.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_creator_poll(env: &Env, creator: &Address, poll_id: u64) {
+    let mut poll_ids = get_creator_polls(env, creator);
+    poll_ids.push_back(poll_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorPolls(creator.clone()), &poll_ids);
+}
+
+pub(crate) fn remove_creator_poll(env: &Env, creator: &Address, poll_id: u64) {
+    let mut poll_ids = get_creator_polls(env, creator);
+    if let Some(i) = poll_ids.first_index_of(poll_id) {
+        poll_ids.remove(i);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorPolls(creator.clone()), &poll_ids);
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Collect what `load` returns for ids `cursor..end`, within the page and scan
+/// caps. Returns the page and the id to resume from, or `0` if none is left.
+fn scan<T>(
+    env: &Env,
+    cursor: u64,
+    end: u64,
+    limit: u32,
+    load: impl Fn(u64) -> Option<T>,
+) -> (Vec<T>, u64)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut page = Vec::new(env);
+    let mut id = cursor.max(1);
+    let stop = end.min(id.saturating_add(MAX_PAGE_SCAN as u64));
+    while id < stop && page.len() < limit.min(MAX_PAGE_SIZE) {
+        if let Some(item) = load(id) {
+            page.push_back(item);
+        }
+        id += 1;
+    }
+    (page, if id < end { id } else { 0 })
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{testutils::Address as _, Address, String};
+    use predictx_shared::{
+        MatchStatus, PollCategory, PollStatus, PredictXError, StakeSide, MAX_PAGE_SCAN,
+        MAX_PAGE_SIZE,
+    };
+    use crate::testutils::TestMarket;
+
+    const KICKOFF: u64 = 1_003_600;
+    const LOCK_TIME: u64 = 1_003_000;
+
+    // ── Matches ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn list_matches_pages_oldest_first() {
+        let s = TestMarket::new();
+        for _ in 0..5 {
+            s.create_match(KICKOFF);
+        }
+
+        let page = s.client.list_matches(&0, &2, &None);
+        assert_eq!(page.matches.len(), 2);
+        assert_eq!(page.matches.get(0).unwrap().match_id, 1);
+        assert_eq!(page.next_cursor, 3);
+
+        let page = s.client.list_matches(&page.next_cursor, &2, &None);
+        assert_eq!(page.matches.get(0).unwrap().match_id, 3);
+        assert_eq!(page.matches.get(1).unwrap().match_id, 4);
+
+        let last = s.client.list_matches(&page.next_cursor, &10, &None);
+        assert_eq!(last.matches.len(), 1);
+        assert_eq!(last.next_cursor, 0);
+        assert_eq!(s.client.list_matches(&6, &10, &None).matches.len(), 0);
+    }
+
+    #[test]
+    fn list_matches_filters_by_status() {
+        let s = TestMarket::new();
+        let cancelled = s.create_match(KICKOFF);
+        s.create_match(KICKOFF);
+        s.client
+            .cancel_match(&s.admin, &cancelled, &String::from_str(&s.env, "Weather"));
+
+        let page = s.client.list_matches(&0, &10, &Some(MatchStatus::Cancelled));
+        assert_eq!(page.matches.len(), 1);
+        assert_eq!(page.matches.get(0).unwrap().match_id, cancelled);
+        let page = s.client.list_matches(&0, &10, &Some(MatchStatus::Scheduled));
+        assert_eq!(page.matches.len(), 1);
+    }
+
+    #[test]
+    fn list_matches_caps_the_page_size() {
+        let s = TestMarket::new();
+        for _ in 0..MAX_PAGE_SIZE + 1 {
+            s.create_match(KICKOFF);
+        }
+        let page = s.client.list_matches(&0, &u32::MAX, &None);
+        assert_eq!(page.matches.len(), MAX_PAGE_SIZE);
+        assert_eq!(page.next_cursor, MAX_PAGE_SIZE as u64 + 1);
+    }
+
+    #[test]
+    fn list_matches_caps_the_ids_scanned() {
+        let s = TestMarket::new();
+        for _ in 0..MAX_PAGE_SCAN {
+            s.create_match(KICKOFF);
+        }
+        let cancelled = s.create_match(KICKOFF);
+        s.client
+            .cancel_match(&s.admin, &cancelled, &String::from_str(&s.env, "Weather"));
+
+        // The first call gives up before reaching the only match that matches
+        let page = s.client.list_matches(&0, &10, &Some(MatchStatus::Cancelled));
+        assert_eq!(page.matches.len(), 0);
+        assert_eq!(page.next_cursor, cancelled);
+
+        let page = s.client.list_matches(&page.next_cursor, &10, &Some(MatchStatus::Cancelled));
+        assert_eq!(page.matches.get(0).unwrap().match_id, cancelled);
+        assert_eq!(page.next_cursor, 0);
+    }
+
+    // ── Polls ─────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn list_polls_filters_by_category_and_status() {
+        let s = TestMarket::new();
+        let creator = Address::generate(&s.env);
+        let open_match = s.create_match(KICKOFF);
+        let cancelled_match = s.create_match(KICKOFF);
+        let player = s.create_poll(&creator, open_match, PollCategory::PlayerEvent, LOCK_TIME);
+        let team = s.create_poll(&creator, open_match, PollCategory::TeamEvent, LOCK_TIME);
+        let cancelled = s.create_poll(&creator, cancelled_match, PollCategory::TeamEvent, LOCK_TIME);
+        s.client
+            .cancel_match(&s.admin, &cancelled_match, &String::from_str(&s.env, "Weather"));
+
+        let teams = s.client.list_polls(&0, &10, &None, &Some(PollCategory::TeamEvent)).polls;
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams.get(0).unwrap().poll_id, team);
+        assert_eq!(teams.get(1).unwrap().poll_id, cancelled);
+
+        let active = s.client.list_polls(&0, &10, &Some(PollStatus::Active), &None).polls;
+        assert_eq!(active.len(), 2);
+        assert_eq!(active.get(0).unwrap().poll_id, player);
+
+        let both = s.client.list_polls(
+            &0,
+            &10,
+            &Some(PollStatus::Active),
+            &Some(PollCategory::TeamEvent),
+        );
+        assert_eq!(both.polls.len(), 1);
+        assert_eq!(both.polls.get(0).unwrap().poll_id, team);
+
+        let first = s.client.list_polls(&0, &1, &Some(PollStatus::Active), &None);
+        assert_eq!(first.next_cursor, team);
+        let rest = s.client.list_polls(&first.next_cursor, &10, &Some(PollStatus::Active), &None);
+        assert_eq!(rest.polls.len(), 1);
+        assert_eq!(rest.polls.get(0).unwrap().poll_id, team);
+        assert_eq!(rest.next_cursor, 0);
+    }
+
+    #[test]
+    fn list_polls_by_creator_skips_deleted_polls() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let alice = Address::generate(&s.env);
+        let bob = Address::generate(&s.env);
+        let first = s.create_poll(&alice, match_id, PollCategory::PlayerEvent, LOCK_TIME);
+        s.create_poll(&bob, match_id, PollCategory::PlayerEvent, LOCK_TIME);
+        let deleted = s.create_poll(&alice, match_id, PollCategory::Other, LOCK_TIME);
+        let last = s.create_poll(&alice, match_id, PollCategory::TeamEvent, LOCK_TIME);
+        s.client.delete_poll(&alice, &deleted);
+
+        let polls = s.client.list_polls_by_creator(&alice, &0, &10);
+        assert_eq!(polls.len(), 2);
+        assert_eq!(polls.get(0).unwrap().poll_id, first);
+        assert_eq!(polls.get(1).unwrap().poll_id, last);
+
+        let page = s.client.list_polls_by_creator(&alice, &1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().poll_id, last);
+
+        assert_eq!(s.client.list_polls(&0, &10, &None, &None).polls.len(), 3);
+    }
+
+    // ── Match summary ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn match_summary_includes_polls_with_pools_and_status() {
+        let s = TestMarket::new();
+        let match_id = s.create_match(KICKOFF);
+        let creator = Address::generate(&s.env);
+        let staked = s.create_poll(&creator, match_id, PollCategory::PlayerEvent, LOCK_TIME);
+        let empty = s.create_poll(&creator, match_id, PollCategory::TeamEvent, LOCK_TIME);
+        s.stake_as_new_user(staked, 30_000_000, StakeSide::Yes);
+        s.stake_as_new_user(staked, 20_000_000, StakeSide::No);
+
+        let summary = s.client.get_match_summary(&match_id);
+        assert_eq!(summary.details, s.client.get_match(&match_id));
+        assert_eq!(summary.polls.len(), 2);
+
+        let first = summary.polls.get(0).unwrap();
+        assert_eq!(first.poll_id, staked);
+        assert_eq!(first.status, PollStatus::Active);
+        assert_eq!(first.outcome_pools, s.client.get_pool_info(&staked).outcome_pools);
+        assert_eq!(first.outcome_pools.get(0).unwrap(), 30_000_000);
+        assert_eq!(summary.polls.get(1).unwrap().poll_id, empty);
+    }
+
+    #[test]
+    fn match_summary_of_unknown_match_fails() {
+        let s = TestMarket::new();
+        let err = s.client.try_get_match_summary(&42).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchNotFound);
+    }
+}
@@ -1,31 +1,29 @@
 #![no_std]
 
+mod bonds;
+mod claims;
+mod conditions;
+mod discovery;
+mod lifecycle;
+mod limits;
 mod matches;
+mod resolution;
 mod staking;
+#[cfg(test)]
+mod testutils;
 pub(crate) mod token_utils;
 
 use predictx_shared::{
-    Match, PlatformStats, Poll, PollCategory, PollStatus, PredictXError, Stake, StakeSide,
-    MAX_POLLS_PER_MATCH,
+    Match, MatchEvent, MatchStatus, PlatformStats, Poll, PollCategory, PollCondition, PollOutcome,
+    PollStatus, PredictXError, Score, Stake, StakeEntry, StakeSide, BPS_DENOMINATOR, MAX_POLLS_PER_MATCH,
+    MAX_LOCK_AFTER_KICKOFF_SECS, MAX_POLL_OUTCOMES, MAX_QUESTION_LENGTH,
 };
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, String, Symbol, Vec};
 
-mod voting_oracle {
+pub(crate) mod voting_oracle {
     soroban_sdk::contractimport!(file = "wasm/voting_oracle.wasm");
 }
 
-fn map_oracle_poll_status(status: voting_oracle::PollStatus) -> PollStatus {
-    match status {
-        voting_oracle::PollStatus::Active      => PollStatus::Active,
-        voting_oracle::PollStatus::Locked      => PollStatus::Locked,
-        voting_oracle::PollStatus::Voting      => PollStatus::Voting,
-        voting_oracle::PollStatus::AdminReview => PollStatus::AdminReview,
-        voting_oracle::PollStatus::Disputed    => PollStatus::Disputed,
-        voting_oracle::PollStatus::Resolved    => PollStatus::Resolved,
-        voting_oracle::PollStatus::Cancelled   => PollStatus::Cancelled,
-    }
-}
-
 #[contract]
 pub struct PredictionMarket;
 
@@ -39,6 +37,16 @@ pub enum DataKey {
     TokenAddress,
     TreasuryAddress,
     PlatformFeeBps,
+    /// Fee on withdrawing a stake before lock; `DEFAULT_EXIT_FEE_BPS` if unset.
+    ExitFeeBps,
+    /// Poll creator's share of winning payouts.
+    CreatorFeeBps,
+    /// Creator fees accrued to a creator and not yet claimed.
+    CreatorFees(Address),
+    /// Lifetime creator fees accrued to a creator.
+    CreatorEarnings(Address),
+    /// Ids of the polls a creator has created and not deleted.
+    CreatorPolls(Address),
     Stake(u64, Address),
     EmergencyClaimed(u64, Address),
     PlatformStats,
@@ -48,20 +56,95 @@ pub enum DataKey {
     NextPollId,
     Match(u64),
     MatchPolls(u64),
+    /// Live event log of a match.
+    MatchEvents(u64),
+    /// Data-feed account allowed to log match events alongside the admin.
+    MatchFeeder,
     // ── poll & staking keys ───────────────────────────────────────────────────
     Poll(u64),
     UserStakes(Address),
     HasStaked(u64, Address),
+    /// A hedged position on an outcome other than the user's first stake.
+    HedgeStake(u64, Address, u32),
+    /// Every stake placement a user made on a poll.
+    StakeHistory(u64, Address),
+    /// Whether a poll's stakers may back more than one outcome.
+    HedgingEnabled(u64),
+    /// Platform-wide `StakeLimits`.
+    StakeLimits,
+    /// Single-stake bounds a poll's creator set.
+    PollStakeLimits(u64),
+    /// A user's running stake total across every poll of a match.
+    UserMatchStake(u64, Address),
+    /// Bond required to create a poll of a category.
+    CreatorBond(PollCategory),
+    /// Creator bond held against a poll until it is settled.
+    PollBond(u64),
+    VoterRewardPool(u64),
+    /// Structured condition a poll was created with, if any.
+    PollCondition(u64),
 }
 
 /// Pool state returned by `get_pool_info`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PoolInfo {
-    pub yes_pool: i128,
-    pub no_pool: i128,
-    pub yes_count: u32,
-    pub no_count: u32,
+    /// Total staked on each outcome, indexed like `Poll.outcomes`.
+    pub outcome_pools: Vec<i128>,
+    /// Number of stakers on each outcome.
+    pub outcome_counts: Vec<u32>,
+}
+
+/// A match with every poll on it, returned by `get_match_summary`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchSummary {
+    pub details: Match,
+    /// Each poll on the match, pools and status included.
+    pub polls: Vec<Poll>,
+}
+
+/// One page of `list_matches`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchPage {
+    pub matches: Vec<Match>,
+    /// `cursor` for the next page; `0` once every match has been looked at.
+    pub next_cursor: u64,
+}
+
+/// One page of `list_polls`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PollPage {
+    pub polls: Vec<Poll>,
+    /// `cursor` for the next page; `0` once every poll has been looked at.
+    pub next_cursor: u64,
+}
+
+/// Platform-wide stake limits set by the admin. A cap of `0` means uncapped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeLimits {
+    /// Smallest single stake any poll accepts.
+    pub min_stake: i128,
+    /// Largest single stake any poll accepts.
+    pub max_stake: i128,
+    /// Most one user may have staked on a single poll.
+    pub max_user_poll_stake: i128,
+    /// Most one user may have staked across all polls of a match.
+    pub max_user_match_stake: i128,
+    /// Most a single poll's pools may hold in total.
+    pub max_poll_pool: i128,
+}
+
+/// Single-stake bounds of one poll, set by its creator within `StakeLimits`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PollStakeLimits {
+    pub min_stake: i128,
+    /// `0` means no maximum.
+    pub max_stake: i128,
 }
 
 fn get_admin(env: &Env) -> Result<Address, PredictXError> {
@@ -69,7 +152,7 @@ fn get_admin(env: &Env) -> Result<Address, PredictXError> {
         .ok_or(PredictXError::NotInitialized)
 }
 
-fn get_oracle(env: &Env) -> Result<Address, PredictXError> {
+pub(crate) fn get_oracle(env: &Env) -> Result<Address, PredictXError> {
     env.storage().instance().get(&DataKey::VotingOracle)
         .ok_or(PredictXError::NotInitialized)
 }
@@ -100,10 +183,6 @@ pub(crate) fn set_platform_stats(env: &Env, stats: &PlatformStats) {
     env.storage().instance().set(&DataKey::PlatformStats, stats);
 }
 
-fn load_stake(env: &Env, poll_id: u64, user: &Address) -> Option<Stake> {
-    env.storage().persistent().get(&DataKey::Stake(poll_id, user.clone()))
-}
-
 fn has_emergency_claimed(env: &Env, poll_id: u64, user: &Address) -> bool {
     env.storage().persistent()
         .get(&DataKey::EmergencyClaimed(poll_id, user.clone()))
@@ -115,6 +194,274 @@ fn set_emergency_claimed(env: &Env, poll_id: u64, user: &Address) {
         .set(&DataKey::EmergencyClaimed(poll_id, user.clone()), &true);
 }
 
+#[allow(clippy::too_many_arguments)]
+fn create_poll(
+    env: &Env,
+    creator: Address,
+    match_id: u64,
+    question: String,
+    category: PollCategory,
+    condition: Option<PollCondition>,
+    outcomes: Vec<String>,
+    lock_time: u64,
+) -> Result<u64, PredictXError> {
+    ensure_not_paused(env)?;
+    creator.require_auth();
+
+    if question.len() > MAX_QUESTION_LENGTH {
+        return Err(PredictXError::PollQuestionTooLong);
+    }
+    if outcomes.len() < 2 || outcomes.len() > MAX_POLL_OUTCOMES {
+        return Err(PredictXError::InvalidOutcome);
+    }
+    for label in outcomes.iter() {
+        if label.is_empty() || label.len() > MAX_QUESTION_LENGTH {
+            return Err(PredictXError::InvalidOutcome);
+        }
+    }
+
+    // A structured condition must match the poll's category and shape
+    if let Some(c) = &condition {
+        if !conditions::fits_category(c, category) {
+            return Err(PredictXError::InvalidPollCategory);
+        }
+        if conditions::outcome_count(c) != outcomes.len() {
+            return Err(PredictXError::InvalidOutcome);
+        }
+    }
+
+    // Validate match exists and is still on
+    let m: Match = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Match(match_id))
+        .ok_or(PredictXError::MatchNotFound)?;
+    match m.status {
+        MatchStatus::Cancelled | MatchStatus::Abandoned => return Err(PredictXError::MatchCancelled),
+        MatchStatus::Finished => return Err(PredictXError::MatchAlreadyFinished),
+        _ => {}
+    }
+
+    validate_lock_time(env, &m, lock_time)?;
+
+    // Check max polls per match
+    let mut match_polls: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MatchPolls(match_id))
+        .unwrap_or(Vec::new(env));
+    if match_polls.len() >= MAX_POLLS_PER_MATCH {
+        return Err(PredictXError::MaxPollsPerMatchReached);
+    }
+
+    let poll_id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextPollId)
+        .unwrap_or(1);
+
+    let mut outcome_pools = Vec::new(env);
+    let mut outcome_counts = Vec::new(env);
+    for _ in 0..outcomes.len() {
+        outcome_pools.push_back(0_i128);
+        outcome_counts.push_back(0_u32);
+    }
+
+    bonds::post(env, &creator, poll_id, category)?;
+
+    let poll = Poll {
+        poll_id,
+        match_id,
+        creator: creator.clone(),
+        question,
+        category,
+        lock_time,
+        outcomes,
+        outcome_pools,
+        outcome_counts,
+        status: PollStatus::Active,
+        outcome: PollOutcome::Pending,
+        resolution_time: 0,
+        created_at: env.ledger().timestamp(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Poll(poll_id), &poll);
+    if let Some(condition) = condition {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PollCondition(poll_id), &condition);
+    }
+
+    match_polls.push_back(poll_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MatchPolls(match_id), &match_polls);
+    discovery::add_creator_poll(env, &creator, poll_id);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::NextPollId, &(poll_id + 1));
+
+    let mut stats = get_platform_stats(env);
+    stats.total_polls_created += 1;
+    set_platform_stats(env, &stats);
+
+    env.events()
+        .publish((Symbol::new(env, "PollCreated"), poll_id), ());
+
+    Ok(poll_id)
+}
+
+fn cancel_poll(env: &Env, admin: Address, poll_id: u64, invalid: bool) -> Result<(), PredictXError> {
+    ensure_not_paused(env)?;
+    let stored_admin = get_admin(env)?;
+    if admin != stored_admin { return Err(PredictXError::Unauthorized); }
+    admin.require_auth();
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    // Winners of a settled poll are owed the losing pool; cancelling it would
+    // let losers take their stakes back out of it.
+    match poll.status {
+        PollStatus::Resolved => return Err(PredictXError::PollAlreadyResolved),
+        PollStatus::Disputed => return Err(PredictXError::DisputeAlreadyOpen),
+        _ => {}
+    }
+    if lifecycle::cancel(env, poll_id, invalid)? {
+        env.events().publish((Symbol::new(env, "PollCancelled"),), poll_id);
+    }
+    Ok(())
+}
+
+/// Whether stakers may pull their stakes out of a poll through
+/// `emergency_withdraw`: it was cancelled, or it has been stuck short of a
+/// final outcome for `EMERGENCY_TIMEOUT_SECS`.
+fn emergency_eligible(env: &Env, poll: &Poll) -> bool {
+    let stuck_since = match poll.status {
+        PollStatus::Cancelled => return true,
+        PollStatus::Locked | PollStatus::Voting | PollStatus::AdminReview => poll.lock_time,
+        PollStatus::Disputed => poll.resolution_time,
+        PollStatus::Active | PollStatus::Resolved => return false,
+    };
+    env.ledger().timestamp().saturating_sub(stuck_since) >= EMERGENCY_TIMEOUT_SECS
+}
+
+/// A poll must lock in the future and, for in-play polls, not too far past
+/// its match's kickoff.
+fn validate_lock_time(env: &Env, m: &Match, lock_time: u64) -> Result<(), PredictXError> {
+    if lock_time <= env.ledger().timestamp()
+        || lock_time > m.kickoff_time + MAX_LOCK_AFTER_KICKOFF_SECS
+    {
+        return Err(PredictXError::InvalidLockTime);
+    }
+    Ok(())
+}
+
+/// Load a poll for its creator to edit: it must still be `Active` and nobody
+/// may have staked on it yet.
+fn load_editable_poll(env: &Env, creator: &Address, poll_id: u64) -> Result<Poll, PredictXError> {
+    ensure_not_paused(env)?;
+    creator.require_auth();
+
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    if poll.creator != *creator {
+        return Err(PredictXError::Unauthorized);
+    }
+    if poll.status != PollStatus::Active {
+        return Err(PredictXError::PollNotActive);
+    }
+    if poll.outcome_counts.iter().any(|count| count > 0) {
+        return Err(PredictXError::AlreadyStaked);
+    }
+    Ok(poll)
+}
+
+fn update_poll(
+    env: &Env,
+    creator: Address,
+    poll_id: u64,
+    question: Option<String>,
+    lock_time: Option<u64>,
+    category: Option<PollCategory>,
+) -> Result<Poll, PredictXError> {
+    let mut poll = load_editable_poll(env, &creator, poll_id)?;
+
+    if let Some(question) = question {
+        if question.len() > MAX_QUESTION_LENGTH {
+            return Err(PredictXError::PollQuestionTooLong);
+        }
+        poll.question = question;
+    }
+    if let Some(lock_time) = lock_time {
+        let m: Match = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Match(poll.match_id))
+            .ok_or(PredictXError::MatchNotFound)?;
+        validate_lock_time(env, &m, lock_time)?;
+        poll.lock_time = lock_time;
+    }
+    if let Some(category) = category {
+        if let Some(c) = conditions::get_poll_condition(env, poll_id) {
+            if !conditions::fits_category(&c, category) {
+                return Err(PredictXError::InvalidPollCategory);
+            }
+        }
+        if category != poll.category {
+            bonds::rebond(env, &poll, category)?;
+        }
+        poll.category = category;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Poll(poll_id), &poll);
+    env.events()
+        .publish((Symbol::new(env, "PollUpdated"), poll_id), poll.clone());
+
+    Ok(poll)
+}
+
+fn delete_poll(env: &Env, creator: Address, poll_id: u64) -> Result<(), PredictXError> {
+    let poll = load_editable_poll(env, &creator, poll_id)?;
+
+    let mut match_polls: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MatchPolls(poll.match_id))
+        .unwrap_or(Vec::new(env));
+    if let Some(i) = match_polls.first_index_of(poll_id) {
+        match_polls.remove(i);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::MatchPolls(poll.match_id), &match_polls);
+    discovery::remove_creator_poll(env, &creator, poll_id);
+
+    for key in [
+        DataKey::Poll(poll_id),
+        DataKey::PollCondition(poll_id),
+        DataKey::PollStakeLimits(poll_id),
+        DataKey::HedgingEnabled(poll_id),
+    ] {
+        env.storage().persistent().remove(&key);
+    }
+    bonds::release(env, &poll)?;
+
+    env.events()
+        .publish((Symbol::new(env, "PollDeleted"), poll_id), poll.match_id);
+
+    Ok(())
+}
+
 const EMERGENCY_TIMEOUT_SECS: u64 = 7 * 24 * 60 * 60;
 
 #[contractimpl]
@@ -173,36 +520,22 @@ impl PredictionMarket {
 
     pub fn is_paused(env: Env) -> bool { is_paused(&env) }
 
-    pub fn oracle_poll_status(env: Env, poll_id: u64) -> Result<PollStatus, PredictXError> {
-        let oracle_id = get_oracle(&env)?;
-        let client = voting_oracle::Client::new(&env, &oracle_id);
-        Ok(map_oracle_poll_status(client.get_poll_status(&poll_id)))
+    /// Cancel a poll and return its creator's bond.
+    pub fn cancel_poll(env: Env, admin: Address, poll_id: u64) -> Result<(), PredictXError> {
+        cancel_poll(&env, admin, poll_id, false)
     }
 
-    pub fn cancel_poll(env: Env, admin: Address, poll_id: u64) -> Result<(), PredictXError> {
-        ensure_not_paused(&env)?;
-        let stored_admin = get_admin(&env)?;
-        if admin != stored_admin { return Err(PredictXError::Unauthorized); }
-        admin.require_auth();
-        let oracle_id = get_oracle(&env)?;
-        let client = voting_oracle::Client::new(&env, &oracle_id);
-        client.set_poll_status(&poll_id, &voting_oracle::PollStatus::Cancelled);
-        env.events().publish((Symbol::new(&env, "PollCancelled"),), poll_id);
-        Ok(())
+    /// Cancel a poll as invalid (e.g. ambiguous or unanswerable) and slash its
+    /// creator's bond to the treasury.
+    pub fn cancel_invalid_poll(env: Env, admin: Address, poll_id: u64) -> Result<(), PredictXError> {
+        cancel_poll(&env, admin, poll_id, true)
     }
 
     pub fn check_emergency_eligible(env: Env, poll_id: u64) -> bool {
-        let oracle_id = match get_oracle(&env) {
-            Ok(id) => id,
-            Err(_) => return false,
-        };
-        let client = voting_oracle::Client::new(&env, &oracle_id);
-        let status = map_oracle_poll_status(client.get_poll_status(&poll_id));
-        if status == PollStatus::Cancelled { return true; }
-        if status != PollStatus::Disputed && status != PollStatus::Locked { return false; }
-        let updated_at = client.get_poll_status_updated_at(&poll_id);
-        if updated_at == 0 { return false; }
-        env.ledger().timestamp().saturating_sub(updated_at) >= EMERGENCY_TIMEOUT_SECS
+        env.storage()
+            .persistent()
+            .get(&DataKey::Poll(poll_id))
+            .is_some_and(|poll| emergency_eligible(&env, &poll))
     }
 
     pub fn emergency_withdraw(env: Env, user: Address, poll_id: u64) -> Result<i128, PredictXError> {
@@ -210,29 +543,23 @@ impl PredictionMarket {
         if has_emergency_claimed(&env, poll_id, &user) {
             return Err(PredictXError::AlreadyClaimed);
         }
-        let oracle_id = get_oracle(&env)?;
-        let client = voting_oracle::Client::new(&env, &oracle_id);
-        let status = map_oracle_poll_status(client.get_poll_status(&poll_id));
-        let eligible = if status == PollStatus::Cancelled {
-            true
-        } else if status == PollStatus::Disputed || status == PollStatus::Locked {
-            let updated_at = client.get_poll_status_updated_at(&poll_id);
-            updated_at != 0 && env.ledger().timestamp().saturating_sub(updated_at) >= EMERGENCY_TIMEOUT_SECS
-        } else {
-            false
-        };
-        if !eligible { return Err(PredictXError::EmergencyWithdrawNotAllowed); }
-        let stake = load_stake(&env, poll_id, &user).ok_or(PredictXError::NotStaker)?;
+        let poll: Poll = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Poll(poll_id))
+            .ok_or(PredictXError::PollNotFound)?;
+        if !emergency_eligible(&env, &poll) { return Err(PredictXError::EmergencyWithdrawNotAllowed); }
+        let amount = staking::take_refund(&env, poll_id, &user)?;
         set_emergency_claimed(&env, poll_id, &user);
 
         // Transfer tokens back to user
-        token_utils::transfer_from_contract(&env, &user, stake.amount)?;
+        token_utils::transfer_from_contract(&env, &user, amount)?;
 
         let mut stats = get_platform_stats(&env);
-        stats.total_value_locked -= stake.amount;
+        stats.total_value_locked -= amount;
         set_platform_stats(&env, &stats);
-        env.events().publish((Symbol::new(&env, "EmergencyWithdrawal"), poll_id, user.clone()), stake.amount);
-        Ok(stake.amount)
+        env.events().publish((Symbol::new(&env, "EmergencyWithdrawal"), poll_id, user.clone()), amount);
+        Ok(amount)
     }
 
     // ── Poll management ──────────────────────────────────────────────────────
@@ -243,84 +570,106 @@ impl PredictionMarket {
         match_id: u64,
         question: String,
         category: PollCategory,
+        condition: Option<PollCondition>,
         lock_time: u64,
     ) -> Result<u64, PredictXError> {
-        ensure_not_paused(&env)?;
-        creator.require_auth();
-
-        // Validate match exists
-        let _m: Match = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(PredictXError::MatchNotFound)?;
+        let outcomes = vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")];
+        create_poll(&env, creator, match_id, question, category, condition, outcomes, lock_time)
+    }
 
-        // Validate lock_time is in the future
-        if lock_time <= env.ledger().timestamp() {
-            return Err(PredictXError::InvalidLockTime);
-        }
+    /// Create a poll with 2..=`MAX_POLL_OUTCOMES` named outcomes, e.g.
+    /// home/draw/away or a list of first goalscorers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_categorical_poll(
+        env: Env,
+        creator: Address,
+        match_id: u64,
+        question: String,
+        category: PollCategory,
+        condition: Option<PollCondition>,
+        outcomes: Vec<String>,
+        lock_time: u64,
+    ) -> Result<u64, PredictXError> {
+        create_poll(&env, creator, match_id, question, category, condition, outcomes, lock_time)
+    }
 
-        // Check max polls per match
-        let mut match_polls: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MatchPolls(match_id))
-            .unwrap_or(Vec::new(&env));
-        if match_polls.len() >= MAX_POLLS_PER_MATCH {
-            return Err(PredictXError::MaxPollsPerMatchReached);
-        }
+    /// Fix a poll's question, lock time or category. Creator-only, and only
+    /// while the poll is `Active` with no stakes on it.
+    pub fn update_poll(
+        env: Env,
+        creator: Address,
+        poll_id: u64,
+        question: Option<String>,
+        lock_time: Option<u64>,
+        category: Option<PollCategory>,
+    ) -> Result<Poll, PredictXError> {
+        update_poll(&env, creator, poll_id, question, lock_time, category)
+    }
 
-        let poll_id: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::NextPollId)
-            .unwrap_or(1);
-
-        let poll = Poll {
-            poll_id,
-            match_id,
-            creator: creator.clone(),
-            question,
-            category,
-            lock_time,
-            yes_pool: 0,
-            no_pool: 0,
-            yes_count: 0,
-            no_count: 0,
-            status: PollStatus::Active,
-            outcome: None,
-            resolution_time: 0,
-            created_at: env.ledger().timestamp(),
-        };
+    /// Remove a poll nobody has staked on, freeing its slot on the match and
+    /// returning the creator's bond. Same rules as `update_poll`.
+    pub fn delete_poll(env: Env, creator: Address, poll_id: u64) -> Result<(), PredictXError> {
+        delete_poll(&env, creator, poll_id)
+    }
 
+    pub fn get_poll(env: Env, poll_id: u64) -> Result<Poll, PredictXError> {
         env.storage()
             .persistent()
-            .set(&DataKey::Poll(poll_id), &poll);
+            .get(&DataKey::Poll(poll_id))
+            .ok_or(PredictXError::PollNotFound)
+    }
 
-        match_polls.push_back(poll_id);
-        env.storage()
-            .persistent()
-            .set(&DataKey::MatchPolls(match_id), &match_polls);
+    /// Structured condition of a poll, or `None` if it is settled by voting.
+    pub fn get_poll_condition(env: Env, poll_id: u64) -> Option<PollCondition> {
+        conditions::get_poll_condition(&env, poll_id)
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::NextPollId, &(poll_id + 1));
+    /// Record a poll's outcome. Callable only by the configured voting oracle.
+    pub fn resolve_poll(env: Env, poll_id: u64, outcome: bool) -> Result<(), PredictXError> {
+        resolution::resolve_poll(&env, poll_id, outcome)
+    }
 
-        let mut stats = get_platform_stats(&env);
-        stats.total_polls_created += 1;
-        set_platform_stats(&env, &stats);
+    /// Record the winning outcome index of a poll. Callable only by the voting
+    /// oracle; this is how categorical polls are settled.
+    pub fn resolve_poll_outcome(env: Env, poll_id: u64, outcome_index: u32) -> Result<(), PredictXError> {
+        resolution::resolve_poll_outcome(&env, poll_id, outcome_index)
+    }
 
-        env.events()
-            .publish((Symbol::new(&env, "PollCreated"), poll_id), ());
+    /// Settle a poll with no winner; every stake becomes refundable in full
+    /// through `claim_winnings`. Callable only by the voting oracle, which sets
+    /// `slash` when voters found the poll unclear.
+    pub fn void_poll(env: Env, poll_id: u64, slash: bool) -> Result<(), PredictXError> {
+        resolution::void_poll(&env, poll_id, slash)
+    }
 
-        Ok(poll_id)
+    /// Send a contested poll to admin review. Callable only by the voting oracle.
+    pub fn escalate_poll(env: Env, poll_id: u64) -> Result<(), PredictXError> {
+        resolution::escalate_poll(&env, poll_id)
     }
 
-    pub fn get_poll(env: Env, poll_id: u64) -> Result<Poll, PredictXError> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Poll(poll_id))
-            .ok_or(PredictXError::PollNotFound)
+    /// Freeze a resolved poll while the oracle handles a dispute (oracle-only).
+    pub fn mark_disputed(env: Env, poll_id: u64) -> Result<(), PredictXError> {
+        resolution::mark_disputed(&env, poll_id)
+    }
+
+    /// Hand the voter reward share of a resolved poll to the oracle (oracle-only).
+    pub fn fund_voter_rewards(env: Env, poll_id: u64) -> Result<i128, PredictXError> {
+        resolution::fund_voter_rewards(&env, poll_id)
+    }
+
+    pub fn get_voter_reward_pool(env: Env, poll_id: u64) -> i128 {
+        resolution::get_voter_reward_pool(&env, poll_id)
+    }
+
+    // ── Poll lifecycle ────────────────────────────────────────────────────────
+
+    /// Permissionless crank: apply every due time-driven transition for a poll.
+    pub fn advance_poll(env: Env, poll_id: u64) -> Result<PollStatus, PredictXError> {
+        lifecycle::advance_poll(&env, poll_id)
+    }
+
+    pub fn advance_polls(env: Env, poll_ids: Vec<u64>) -> Result<Vec<PollStatus>, PredictXError> {
+        lifecycle::advance_polls(&env, poll_ids)
     }
 
     // ── Staking ───────────────────────────────────────────────────────────────
@@ -332,13 +681,121 @@ impl PredictionMarket {
         amount: i128,
         side: StakeSide,
     ) -> Result<Stake, PredictXError> {
-        staking::stake(&env, staker, poll_id, amount, side)
+        staking::stake(&env, staker, poll_id, side as u32, amount, None)
+    }
+
+    /// Stake like `stake`, but fail with `PayoutBelowMinimum` if the whole
+    /// position would pay out less than `min_payout` should `side` win.
+    pub fn stake_with_min_payout(
+        env: Env,
+        staker: Address,
+        poll_id: u64,
+        amount: i128,
+        side: StakeSide,
+        min_payout: i128,
+    ) -> Result<Stake, PredictXError> {
+        staking::stake(&env, staker, poll_id, side as u32, amount, Some(min_payout))
+    }
+
+    /// Stake on outcome `outcome_index` of a poll; works for binary and
+    /// categorical polls alike.
+    pub fn stake_outcome(
+        env: Env,
+        staker: Address,
+        poll_id: u64,
+        outcome_index: u32,
+        amount: i128,
+    ) -> Result<Stake, PredictXError> {
+        staking::stake(&env, staker, poll_id, outcome_index, amount, None)
     }
 
     pub fn get_stake_info(env: Env, poll_id: u64, user: Address) -> Result<Stake, PredictXError> {
         staking::get_stake_info(&env, poll_id, &user)
     }
 
+    pub fn get_user_positions(env: Env, poll_id: u64, user: Address) -> Vec<Stake> {
+        staking::get_user_positions(&env, poll_id, &user)
+    }
+
+    pub fn get_stake_history(env: Env, poll_id: u64, user: Address) -> Vec<StakeEntry> {
+        staking::get_stake_history(&env, poll_id, &user)
+    }
+
+    /// Let stakers on a poll back more than one outcome (creator-only, while Active).
+    pub fn set_poll_hedging(
+        env: Env,
+        creator: Address,
+        poll_id: u64,
+        enabled: bool,
+    ) -> Result<(), PredictXError> {
+        staking::set_poll_hedging(&env, creator, poll_id, enabled)
+    }
+
+    pub fn is_hedging_enabled(env: Env, poll_id: u64) -> bool {
+        staking::is_hedging_enabled(&env, poll_id)
+    }
+
+    /// Take stake back out of the position on `outcome` before lock, minus the
+    /// exit fee. Returns the amount paid out.
+    pub fn withdraw_stake(
+        env: Env,
+        user: Address,
+        poll_id: u64,
+        outcome: u32,
+        amount: i128,
+    ) -> Result<i128, PredictXError> {
+        staking::withdraw_stake(&env, user, poll_id, outcome, amount)
+    }
+
+    // ── Stake limits ──────────────────────────────────────────────────────────
+
+    pub fn set_stake_limits(env: Env, admin: Address, limits: StakeLimits) -> Result<(), PredictXError> {
+        limits::set_stake_limits(&env, admin, limits)
+    }
+
+    pub fn get_stake_limits(env: Env) -> StakeLimits {
+        limits::get_stake_limits(&env)
+    }
+
+    /// Bound single stakes on a poll (creator-only, within the platform limits).
+    pub fn set_poll_stake_limits(
+        env: Env,
+        creator: Address,
+        poll_id: u64,
+        min_stake: i128,
+        max_stake: i128,
+    ) -> Result<(), PredictXError> {
+        limits::set_poll_stake_limits(&env, creator, poll_id, min_stake, max_stake)
+    }
+
+    pub fn get_poll_stake_limits(env: Env, poll_id: u64) -> PollStakeLimits {
+        limits::get_poll_stake_limits(&env, poll_id)
+    }
+
+    pub fn get_user_match_stake(env: Env, match_id: u64, user: Address) -> i128 {
+        limits::get_user_match_stake(&env, match_id, &user)
+    }
+
+    // ── Creator bonds ─────────────────────────────────────────────────────────
+
+    pub fn set_creator_bond(
+        env: Env,
+        admin: Address,
+        category: PollCategory,
+        amount: i128,
+    ) -> Result<(), PredictXError> {
+        bonds::set_creator_bond(&env, admin, category, amount)
+    }
+
+    pub fn get_creator_bond(env: Env, category: PollCategory) -> i128 {
+        bonds::get_creator_bond(&env, category)
+    }
+
+    /// Bond still held against a poll; `0` once returned or slashed.
+    pub fn get_poll_bond(env: Env, poll_id: u64) -> i128 {
+        bonds::get_poll_bond(&env, poll_id)
+    }
+
     pub fn get_user_stakes(env: Env, user: Address) -> Vec<u64> {
         staking::get_user_stakes(&env, &user)
     }
@@ -353,13 +810,46 @@ impl PredictionMarket {
         side: StakeSide,
         amount: i128,
     ) -> Result<i128, PredictXError> {
-        staking::calculate_potential_winnings(&env, poll_id, side, amount)
+        staking::calculate_potential_winnings(&env, poll_id, side as u32, amount)
+    }
+
+    pub fn calculate_outcome_winnings(
+        env: Env,
+        poll_id: u64,
+        outcome_index: u32,
+        amount: i128,
+    ) -> Result<i128, PredictXError> {
+        staking::calculate_potential_winnings(&env, poll_id, outcome_index, amount)
     }
 
     pub fn get_pool_info(env: Env, poll_id: u64) -> Result<PoolInfo, PredictXError> {
         staking::get_pool_info(&env, poll_id)
     }
 
+    // ── Claims ────────────────────────────────────────────────────────────────
+
+    pub fn claim_winnings(env: Env, user: Address, poll_id: u64) -> Result<i128, PredictXError> {
+        claims::claim_winnings(&env, user, poll_id)
+    }
+
+    pub fn claim_refund(env: Env, user: Address, poll_id: u64) -> Result<i128, PredictXError> {
+        claims::claim_refund(&env, user, poll_id)
+    }
+
+    /// Pay out the creator fees `creator` has accrued across all of their polls.
+    pub fn claim_creator_fees(env: Env, creator: Address) -> Result<i128, PredictXError> {
+        claims::claim_creator_fees(&env, creator)
+    }
+
+    pub fn get_creator_fees(env: Env, creator: Address) -> i128 {
+        claims::get_creator_fees(&env, &creator)
+    }
+
+    /// Lifetime creator fees, claimed or not, for creator leaderboards.
+    pub fn get_creator_earnings(env: Env, creator: Address) -> i128 {
+        claims::get_creator_earnings(&env, &creator)
+    }
+
     pub fn get_platform_stats(env: Env) -> PlatformStats {
         get_platform_stats(&env)
     }
@@ -378,6 +868,38 @@ impl PredictionMarket {
         token_utils::get_platform_fee_bps(&env)
     }
 
+    pub fn get_creator_fee_bps(env: Env) -> u32 {
+        token_utils::get_creator_fee_bps(&env)
+    }
+
+    /// Set the creator's share of winning payouts; together with the platform
+    /// fee it may not exceed 100%.
+    pub fn set_creator_fee_bps(env: Env, admin: Address, creator_fee_bps: u32) -> Result<(), PredictXError> {
+        let stored_admin = get_admin(&env)?;
+        if admin != stored_admin { return Err(PredictXError::Unauthorized); }
+        admin.require_auth();
+        if token_utils::get_platform_fee_bps(&env) + creator_fee_bps > BPS_DENOMINATOR {
+            return Err(PredictXError::InvalidFeeBps);
+        }
+        env.storage().instance().set(&DataKey::CreatorFeeBps, &creator_fee_bps);
+        env.events().publish((Symbol::new(&env, "CreatorFeeUpdated"),), creator_fee_bps);
+        Ok(())
+    }
+
+    pub fn get_exit_fee_bps(env: Env) -> u32 {
+        token_utils::get_exit_fee_bps(&env)
+    }
+
+    pub fn set_exit_fee_bps(env: Env, admin: Address, exit_fee_bps: u32) -> Result<(), PredictXError> {
+        let stored_admin = get_admin(&env)?;
+        if admin != stored_admin { return Err(PredictXError::Unauthorized); }
+        admin.require_auth();
+        if exit_fee_bps > BPS_DENOMINATOR { return Err(PredictXError::InvalidFeeBps); }
+        env.storage().instance().set(&DataKey::ExitFeeBps, &exit_fee_bps);
+        env.events().publish((Symbol::new(&env, "ExitFeeUpdated"),), exit_fee_bps);
+        Ok(())
+    }
+
     pub fn get_contract_balance(env: Env) -> Result<i128, PredictXError> {
         token_utils::get_balance(&env)
     }
@@ -393,6 +915,7 @@ impl PredictionMarket {
         matches::create_match(&env, admin, home_team, away_team, league, venue, kickoff_time)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_match(
         env: Env, admin: Address, match_id: u64,
         home_team: Option<String>, away_team: Option<String>,
@@ -402,8 +925,61 @@ impl PredictionMarket {
         matches::update_match(&env, admin, match_id, home_team, away_team, league, venue, kickoff_time)
     }
 
-    pub fn finish_match(env: Env, admin: Address, match_id: u64) -> Result<(), PredictXError> {
-        matches::finish_match(&env, admin, match_id)
+    /// Record the full-time result; polls with a structured condition resolve from it.
+    pub fn finish_match(
+        env: Env,
+        admin: Address,
+        match_id: u64,
+        final_score: Score,
+        half_time_score: Option<Score>,
+    ) -> Result<(), PredictXError> {
+        matches::finish_match(&env, admin, match_id, final_score, half_time_score)
+    }
+
+    pub fn start_match(env: Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+        matches::start_match(&env, admin, match_id)
+    }
+
+    pub fn start_half_time(env: Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+        matches::start_half_time(&env, admin, match_id)
+    }
+
+    pub fn resume_match(env: Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+        matches::resume_match(&env, admin, match_id)
+    }
+
+    /// Stop a match in play and cancel all of its unresolved polls.
+    pub fn abandon_match(env: Env, admin: Address, match_id: u64, reason: String) -> Result<(), PredictXError> {
+        matches::abandon_match(&env, admin, match_id, reason)
+    }
+
+    /// Call off a match and cancel all of its unresolved polls.
+    pub fn cancel_match(env: Env, admin: Address, match_id: u64, reason: String) -> Result<(), PredictXError> {
+        matches::cancel_match(&env, admin, match_id, reason)
+    }
+
+    /// Move a match to a later kickoff, shifting its polls' lock times.
+    pub fn postpone_match(env: Env, admin: Address, match_id: u64, new_kickoff: u64) -> Result<Match, PredictXError> {
+        matches::postpone_match(&env, admin, match_id, new_kickoff)
+    }
+
+    /// Authorise a data-feed account to log match events.
+    pub fn set_match_feeder(env: Env, admin: Address, feeder: Address) -> Result<(), PredictXError> {
+        matches::set_match_feeder(&env, admin, feeder)
+    }
+
+    /// Append an in-play event to a live match's log (admin or feeder).
+    pub fn record_match_event(
+        env: Env,
+        caller: Address,
+        match_id: u64,
+        event: MatchEvent,
+    ) -> Result<u32, PredictXError> {
+        matches::record_match_event(&env, caller, match_id, event)
+    }
+
+    pub fn get_match_events(env: Env, match_id: u64) -> Result<Vec<MatchEvent>, PredictXError> {
+        matches::get_match_events(&env, match_id)
     }
 
     pub fn get_match(env: Env, match_id: u64) -> Result<Match, PredictXError> {
@@ -417,6 +993,44 @@ impl PredictionMarket {
     pub fn get_match_count(env: Env) -> u64 {
         matches::get_match_count(&env)
     }
+
+    /// A match with every poll on it, pools and status included.
+    pub fn get_match_summary(env: Env, match_id: u64) -> Result<MatchSummary, PredictXError> {
+        discovery::get_match_summary(&env, match_id)
+    }
+
+    // ── Discovery ─────────────────────────────────────────────────────────────
+
+    /// Page through matches, oldest first, optionally only those in `status`,
+    /// starting at match id `cursor`. At most `MAX_PAGE_SIZE` are returned and
+    /// `MAX_PAGE_SCAN` ids looked at per call, so a page may come back short
+    /// with more to follow.
+    pub fn list_matches(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        status: Option<MatchStatus>,
+    ) -> MatchPage {
+        discovery::list_matches(&env, cursor, limit, status)
+    }
+
+    /// Page through polls, oldest first, optionally only those in `status`
+    /// and/or `category`, starting at poll id `cursor`. Bounded like
+    /// `list_matches`.
+    pub fn list_polls(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        status: Option<PollStatus>,
+        category: Option<PollCategory>,
+    ) -> PollPage {
+        discovery::list_polls(&env, cursor, limit, status, category)
+    }
+
+    /// Page through the polls `creator` has created, oldest first.
+    pub fn list_polls_by_creator(env: Env, creator: Address, offset: u32, limit: u32) -> Vec<Poll> {
+        discovery::list_polls_by_creator(&env, &creator, offset, limit)
+    }
 }
 
 #[cfg(test)]
@@ -425,12 +1039,14 @@ extern crate std;
 #[cfg(test)]
 mod test {
     use super::*;
-    use predictx_shared::StakeSide;
+    use crate::testutils::TestMarket;
     use soroban_sdk::testutils::{Address as _, Ledger};
     use soroban_sdk::token;
 
     /// Default platform fee BPS for tests (5%).
     const TEST_FEE_BPS: u32 = 500;
+    /// Lock time of polls made by `create_test_poll`.
+    const TEST_LOCK_TIME: u64 = 1_003_000;
 
     #[test]
     fn initialize_sets_admin_and_oracle() {
@@ -479,24 +1095,7 @@ mod test {
     }
 
     #[test]
-    fn cross_contract_oracle_call_works() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let admin = Address::generate(&env);
-        let oracle_id = env.register(voting_oracle::WASM, ());
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
-        oracle_client.initialize(&admin);
-        oracle_client.set_poll_status(&7_u64, &voting_oracle::PollStatus::Resolved);
-        let contract_id = env.register(PredictionMarket, ());
-        let client = PredictionMarketClient::new(&env, &contract_id);
-        let tok = Address::generate(&env);
-        let treasury = Address::generate(&env);
-        client.initialize(&admin, &oracle_id, &tok, &treasury, &TEST_FEE_BPS);
-        let status = client.oracle_poll_status(&7_u64);
-        assert_eq!(status, PollStatus::Resolved);
-    }
-
-    #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn pause_and_unpause_toggle_contract_state() {
         let env = Env::default();
         env.mock_all_auths();
@@ -516,23 +1115,6 @@ mod test {
         assert_eq!(client.is_paused(), false);
     }
 
-    #[test]
-    fn cancel_poll_sets_cancelled_status_and_emits_event() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let admin = Address::generate(&env);
-        let oracle_id = env.register(voting_oracle::WASM, ());
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
-        oracle_client.initialize(&admin);
-        let contract_id = env.register(PredictionMarket, ());
-        let client = PredictionMarketClient::new(&env, &contract_id);
-        let tok = Address::generate(&env);
-        let treasury = Address::generate(&env);
-        client.initialize(&admin, &oracle_id, &tok, &treasury, &TEST_FEE_BPS);
-        client.cancel_poll(&admin, &1_u64);
-        assert_eq!(oracle_client.get_poll_status(&1_u64), voting_oracle::PollStatus::Cancelled);
-    }
-
     // Helper to set up a real-token environment for emergency withdrawal tests
     fn setup_emergency_env() -> (Env, Address, Address, Address, PredictionMarketClient<'static>) {
         let env = Env::default();
@@ -562,26 +1144,79 @@ mod test {
         sac.mint(to, &amount);
     }
 
+    /// Create a match and a poll on it by `creator`. Returns (match_id, poll_id).
+    fn create_test_poll(env: &Env, client: &PredictionMarketClient, admin: &Address, creator: &Address) -> (u64, u64) {
+        env.ledger().set_timestamp(1_000_000);
+        let match_id = client.create_match(
+            admin,
+            &String::from_str(env, "Arsenal"),
+            &String::from_str(env, "Chelsea"),
+            &String::from_str(env, "Premier League"),
+            &String::from_str(env, "Emirates"),
+            &1_003_600,
+        );
+        let poll_id = client.create_poll(
+            creator,
+            &match_id,
+            &String::from_str(env, "Will Saka score?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &TEST_LOCK_TIME,
+        );
+        (match_id, poll_id)
+    }
+
+    /// Fund a fresh user and stake `amount` on `side`. Returns the user.
+    fn stake_as_new_user(env: &Env, client: &PredictionMarketClient, poll_id: u64, side: StakeSide, amount: i128) -> Address {
+        let user = Address::generate(env);
+        mint_to(env, &client.get_token_address(), &user, amount);
+        client.stake(&user, &poll_id, &amount, &side);
+        user
+    }
+
+    #[test]
+    fn cancel_poll_sets_cancelled_status_and_emits_event() {
+        use soroban_sdk::{testutils::Events, TryIntoVal};
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+
+        client.cancel_poll(&admin, &poll_id);
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        assert_eq!(name, Symbol::new(&env, "PollCancelled"));
+        let cancelled: u64 = data.try_into_val(&env).unwrap();
+        assert_eq!(cancelled, poll_id);
+        assert_eq!(client.get_poll(&poll_id).status, PollStatus::Cancelled);
+    }
+
+    #[test]
+    fn cancel_poll_rejects_settled_polls() {
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, 50_000_000);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::No, 50_000_000);
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 1);
+        client.resolve_poll(&poll_id, &true);
+
+        let err = client.try_cancel_poll(&admin, &poll_id).expect_err("resolved");
+        assert_eq!(err, Ok(PredictXError::PollAlreadyResolved));
+
+        client.mark_disputed(&poll_id);
+        let err = client.try_cancel_poll(&admin, &poll_id).expect_err("disputed");
+        assert_eq!(err, Ok(PredictXError::DisputeAlreadyOpen));
+        assert_eq!(client.get_poll(&poll_id).status, PollStatus::Disputed);
+    }
+
     #[test]
     fn emergency_withdraw_on_cancelled_poll_refunds_stake() {
-        let (env, admin, oracle_id, contract_id, client) = setup_emergency_env();
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
-        let token_addr: Address = env.as_contract(&contract_id, || {
-            env.storage().instance().get(&DataKey::TokenAddress).unwrap()
-        });
-
-        let user = Address::generate(&env);
-        let amount: i128 = 50;
-
-        // Fund the contract so it can transfer back
-        mint_to(&env, &token_addr, &contract_id, amount);
-
-        let stake = Stake { user: user.clone(), poll_id: 10, amount, side: StakeSide::Yes, claimed: false, staked_at: env.ledger().timestamp() };
-        env.as_contract(&contract_id, || {
-            env.storage().persistent().set(&DataKey::Stake(10, user.clone()), &stake);
-        });
-        client.cancel_poll(&admin, &10_u64);
-        let refunded = client.emergency_withdraw(&user, &10_u64);
+        let (env, admin, _oracle_id, contract_id, client) = setup_emergency_env();
+        let token_addr = client.get_token_address();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        let amount: i128 = 50_000_000;
+        let user = stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, amount);
+
+        client.cancel_poll(&admin, &poll_id);
+        let refunded = client.emergency_withdraw(&user, &poll_id);
         assert_eq!(refunded, amount);
 
         // Verify token was transferred
@@ -592,71 +1227,169 @@ mod test {
 
     #[test]
     fn emergency_withdraw_after_dispute_timeout() {
-        let (env, _admin, oracle_id, contract_id, client) = setup_emergency_env();
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
-        let token_addr: Address = env.as_contract(&contract_id, || {
-            env.storage().instance().get(&DataKey::TokenAddress).unwrap()
-        });
-
-        env.ledger().set_timestamp(100);
-        oracle_client.set_poll_status(&5_u64, &voting_oracle::PollStatus::Disputed);
-
-        let user = Address::generate(&env);
-        let amount: i128 = 25;
-        mint_to(&env, &token_addr, &contract_id, amount);
-
-        let stake = Stake { user: user.clone(), poll_id: 5, amount, side: StakeSide::No, claimed: false, staked_at: env.ledger().timestamp() };
-        env.as_contract(&contract_id, || {
-            env.storage().persistent().set(&DataKey::Stake(5, user.clone()), &stake);
-        });
-        env.ledger().set_timestamp(100 + EMERGENCY_TIMEOUT_SECS + 1);
-        assert!(client.check_emergency_eligible(&5_u64));
-        let refunded = client.emergency_withdraw(&user, &5_u64);
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        let amount: i128 = 25_000_000;
+        let user = stake_as_new_user(&env, &client, poll_id, StakeSide::No, amount);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, amount);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 100);
+        client.resolve_poll(&poll_id, &true);
+        client.mark_disputed(&poll_id);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 100 + EMERGENCY_TIMEOUT_SECS + 1);
+        assert!(client.check_emergency_eligible(&poll_id));
+        let refunded = client.emergency_withdraw(&user, &poll_id);
         assert_eq!(refunded, amount);
     }
 
     #[test]
     fn emergency_withdraw_rejected_before_timeout() {
-        let (env, _admin, oracle_id, contract_id, client) = setup_emergency_env();
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        let user = stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, 30_000_000);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::No, 30_000_000);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME);
+        assert_eq!(client.advance_poll(&poll_id), PollStatus::Locked);
+        env.ledger().set_timestamp(TEST_LOCK_TIME + EMERGENCY_TIMEOUT_SECS - 1);
+        assert!(!client.check_emergency_eligible(&poll_id));
+        let err = client.try_emergency_withdraw(&user, &poll_id).expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::EmergencyWithdrawNotAllowed));
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + EMERGENCY_TIMEOUT_SECS);
+        assert!(client.check_emergency_eligible(&poll_id));
+    }
 
-        env.ledger().set_timestamp(200);
-        oracle_client.set_poll_status(&2_u64, &voting_oracle::PollStatus::Locked);
-
-        let user = Address::generate(&env);
-        let stake = Stake { user: user.clone(), poll_id: 2, amount: 30, side: StakeSide::Yes, claimed: false, staked_at: env.ledger().timestamp() };
-        env.as_contract(&contract_id, || {
-            env.storage().persistent().set(&DataKey::Stake(2, user.clone()), &stake);
-        });
-        env.ledger().set_timestamp(200 + EMERGENCY_TIMEOUT_SECS - 1);
-        assert!(!client.check_emergency_eligible(&2_u64));
-        let err = client.try_emergency_withdraw(&user, &2_u64).expect_err("should reject");
+    #[test]
+    fn emergency_withdraw_rejected_on_resolved_poll() {
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, 40_000_000);
+        let loser = stake_as_new_user(&env, &client, poll_id, StakeSide::No, 40_000_000);
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 1);
+        client.resolve_poll(&poll_id, &true);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 1 + EMERGENCY_TIMEOUT_SECS + 1);
+        assert!(client.try_cancel_poll(&admin, &poll_id).is_err());
+        assert!(!client.check_emergency_eligible(&poll_id));
+        let err = client.try_emergency_withdraw(&loser, &poll_id).expect_err("winners are owed this pool");
         assert_eq!(err, Ok(PredictXError::EmergencyWithdrawNotAllowed));
     }
 
     #[test]
     fn emergency_withdraw_prevents_double_withdrawal() {
-        let (env, _admin, oracle_id, contract_id, client) = setup_emergency_env();
-        let oracle_client = voting_oracle::Client::new(&env, &oracle_id);
-        let token_addr: Address = env.as_contract(&contract_id, || {
-            env.storage().instance().get(&DataKey::TokenAddress).unwrap()
-        });
-
-        env.ledger().set_timestamp(300);
-        oracle_client.set_poll_status(&3_u64, &voting_oracle::PollStatus::Disputed);
-
-        let user = Address::generate(&env);
-        let amount: i128 = 40;
-        mint_to(&env, &token_addr, &contract_id, amount);
-
-        let stake = Stake { user: user.clone(), poll_id: 3, amount, side: StakeSide::No, claimed: false, staked_at: env.ledger().timestamp() };
-        env.as_contract(&contract_id, || {
-            env.storage().persistent().set(&DataKey::Stake(3, user.clone()), &stake);
-        });
-        env.ledger().set_timestamp(300 + EMERGENCY_TIMEOUT_SECS + 1);
-        let refunded = client.emergency_withdraw(&user, &3_u64);
+        let (env, admin, _oracle_id, _contract_id, client) = setup_emergency_env();
+        let (_, poll_id) = create_test_poll(&env, &client, &admin, &admin);
+        let amount: i128 = 40_000_000;
+        let user = stake_as_new_user(&env, &client, poll_id, StakeSide::No, amount);
+        stake_as_new_user(&env, &client, poll_id, StakeSide::Yes, amount);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 300);
+        client.resolve_poll(&poll_id, &true);
+        client.mark_disputed(&poll_id);
+
+        env.ledger().set_timestamp(TEST_LOCK_TIME + 300 + EMERGENCY_TIMEOUT_SECS + 1);
+        let refunded = client.emergency_withdraw(&user, &poll_id);
         assert_eq!(refunded, amount);
-        let err = client.try_emergency_withdraw(&user, &3_u64).expect_err("double withdrawal should fail");
+        let err = client.try_emergency_withdraw(&user, &poll_id).expect_err("double withdrawal should fail");
         assert_eq!(err, Ok(PredictXError::AlreadyClaimed));
     }
-}
\ No newline at end of file
+
+    // ── Poll editing ──────────────────────────────────────────
+
+    /// Create a match and a poll on it by `creator`. Returns (match_id, poll_id).
+    fn create_editable_poll(m: &TestMarket, creator: &Address) -> (u64, u64) {
+        let match_id = m.create_match(1_003_600);
+        let poll_id = m.create_poll(creator, match_id, PollCategory::PlayerEvent, 1_003_000);
+        (match_id, poll_id)
+    }
+
+    #[test]
+    fn update_poll_fixes_question_lock_and_category() {
+        use soroban_sdk::{testutils::Events, TryIntoVal};
+        let m = TestMarket::new();
+        let (_, poll_id) = create_editable_poll(&m, &m.admin);
+
+        let poll = m.client.update_poll(
+            &m.admin,
+            &poll_id,
+            &Some(String::from_str(&m.env, "Will Saka score?")),
+            &Some(1_003_500),
+            &Some(PollCategory::TeamEvent),
+        );
+        let (_, topics, _) = m.env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&m.env).unwrap();
+        assert_eq!(name, Symbol::new(&m.env, "PollUpdated"));
+
+        assert_eq!(poll.question, String::from_str(&m.env, "Will Saka score?"));
+        assert_eq!(poll.lock_time, 1_003_500);
+        assert_eq!(poll.category, PollCategory::TeamEvent);
+        assert_eq!(m.client.get_poll(&poll_id), poll);
+
+        let err = m
+            .client
+            .try_update_poll(&m.admin, &poll_id, &None, &Some(999_999), &None)
+            .expect_err("lock time in the past");
+        assert_eq!(err, Ok(PredictXError::InvalidLockTime));
+    }
+
+    #[test]
+    fn update_poll_rejected_once_staked_or_by_stranger() {
+        let m = TestMarket::new();
+        let (_, poll_id) = create_editable_poll(&m, &m.admin);
+
+        let err = m
+            .client
+            .try_update_poll(&Address::generate(&m.env), &poll_id, &None, &None, &None)
+            .expect_err("not the creator");
+        assert_eq!(err, Ok(PredictXError::Unauthorized));
+
+        m.stake_as_new_user(poll_id, 10_000_000, StakeSide::Yes);
+
+        let err = m
+            .client
+            .try_update_poll(&m.admin, &poll_id, &None, &None, &Some(PollCategory::Other))
+            .expect_err("already staked");
+        assert_eq!(err, Ok(PredictXError::AlreadyStaked));
+        let err = m.client.try_delete_poll(&m.admin, &poll_id).expect_err("already staked");
+        assert_eq!(err, Ok(PredictXError::AlreadyStaked));
+    }
+
+    #[test]
+    fn delete_poll_frees_its_match_slot() {
+        use soroban_sdk::{testutils::Events, TryIntoVal};
+        let m = TestMarket::new();
+        let (match_id, poll_id) = create_editable_poll(&m, &m.admin);
+        assert_eq!(m.client.get_platform_stats().total_polls_created, 1);
+
+        m.client.delete_poll(&m.admin, &poll_id);
+        let (_, topics, _) = m.env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&m.env).unwrap();
+        assert_eq!(name, Symbol::new(&m.env, "PollDeleted"));
+
+        assert_eq!(m.client.get_match_polls(&match_id).len(), 0);
+        // Poll ids are never reused, so the lifetime count keeps the deleted poll
+        assert_eq!(m.client.get_platform_stats().total_polls_created, 1);
+        let err = m.client.try_get_poll(&poll_id).expect_err("deleted");
+        assert_eq!(err, Ok(PredictXError::PollNotFound));
+    }
+
+    #[test]
+    fn editing_and_deleting_a_poll_settles_its_bond() {
+        let m = TestMarket::new();
+        m.client.set_creator_bond(&m.admin, &PollCategory::PlayerEvent, &50_000_000);
+        m.client.set_creator_bond(&m.admin, &PollCategory::TeamEvent, &80_000_000);
+        let creator = Address::generate(&m.env);
+        m.mint(&creator, 80_000_000);
+        let (_, poll_id) = create_editable_poll(&m, &creator);
+
+        m.client.update_poll(&creator, &poll_id, &None, &None, &Some(PollCategory::TeamEvent));
+        assert_eq!(m.client.get_poll_bond(&poll_id), 80_000_000);
+        assert_eq!(m.balance(&creator), 0);
+
+        m.client.delete_poll(&creator, &poll_id);
+        assert_eq!(m.client.get_poll_bond(&poll_id), 0);
+        assert_eq!(m.balance(&creator), 80_000_000);
+    }
+}
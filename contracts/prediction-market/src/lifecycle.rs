@@ -0,0 +1,425 @@
+use soroban_sdk::{Env, Symbol, Vec};
+use predictx_shared::{Match, MatchStatus, Poll, PollOutcome, PollStatus, PredictXError};
+use crate::{bonds, DataKey, ensure_not_paused, get_oracle, resolution, voting_oracle};
+
+// ── Poll state machine ────────────────────────────────────────────────────────
+
+/// Apply every time-driven transition that is currently due for a poll.
+///
+/// Permissionless: anyone (typically a keeper bot) may crank a poll forward.
+/// Transitions are applied in order until none is due:
+///
+/// 1. `Active`  → `Locked` once `lock_time` has passed. A poll whose stakers
+///    all backed the same outcome is voided right away, so every stake is
+///    refunded without a fee or a vote.
+/// 2. `Locked`  → `Voting` once the parent match is `Finished`; this opens
+///    the voting window on the `VotingOracle`. Categorical polls skip the
+///    Yes/No vote and go to `AdminReview` instead.
+/// 3. `Voting`  → `Resolved` or `AdminReview` once the oracle's
+///    `VoteTally.voting_end_time` has passed, according to the oracle's
+///    consensus thresholds. Auto-resolution also funds the voter rewards; an
+///    overwhelming "Unclear" vote voids the poll instead. A vote below the
+///    admin-review threshold fails with `ConsensusNotReached` and leaves the
+///    poll in `Voting` for the oracle's admins to settle.
+///
+/// The crank reads the oracle's consensus rather than calling
+/// `VotingOracle::finalize_voting`, which calls back into this contract and
+/// would be rejected as re-entry.
+///
+/// Returns the poll's status after cranking.
+pub fn advance_poll(env: &Env, poll_id: u64) -> Result<PollStatus, PredictXError> {
+    ensure_not_paused(env)?;
+
+    let mut poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+
+    let start_status = poll.status;
+    while apply_next_transition(env, &mut poll)? {}
+
+    if poll.status != start_status {
+        env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+    }
+    Ok(poll.status)
+}
+
+/// Crank several polls in one call. Returns the resulting status of each poll,
+/// in the same order as `poll_ids`.
+pub fn advance_polls(env: &Env, poll_ids: Vec<u64>) -> Result<Vec<PollStatus>, PredictXError> {
+    let mut statuses = Vec::new(env);
+    for poll_id in poll_ids.iter() {
+        statuses.push_back(advance_poll(env, poll_id)?);
+    }
+    Ok(statuses)
+}
+
+/// Cancel a poll that has not been settled yet. Returns `true` if the poll
+/// moved to `Cancelled`; resolved, disputed and already-cancelled polls are
+/// left alone.
+///
+/// The creator's bond is slashed if the poll is being cancelled as `invalid`,
+/// and returned otherwise.
+pub(crate) fn cancel(env: &Env, poll_id: u64, invalid: bool) -> Result<bool, PredictXError> {
+    let mut poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+
+    match poll.status {
+        PollStatus::Active | PollStatus::Locked | PollStatus::Voting | PollStatus::AdminReview => {
+            poll.status = PollStatus::Cancelled;
+            env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+            if invalid {
+                bonds::slash(env, &poll)?;
+            } else {
+                bonds::release(env, &poll)?;
+            }
+            Ok(true)
+        }
+        PollStatus::Resolved | PollStatus::Disputed | PollStatus::Cancelled => Ok(false),
+    }
+}
+
+/// Apply a single transition if one is due. Returns `true` if the poll moved.
+fn apply_next_transition(env: &Env, poll: &mut Poll) -> Result<bool, PredictXError> {
+    let now = env.ledger().timestamp();
+
+    match poll.status {
+        PollStatus::Active if now >= poll.lock_time => {
+            poll.status = PollStatus::Locked;
+            env.events().publish(
+                (Symbol::new(env, "PollLocked"), poll.poll_id),
+                now,
+            );
+            // Nobody took the other side: refund everyone instead of voting.
+            if resolution::is_one_sided(poll) {
+                resolution::apply_resolution(env, poll, PollOutcome::Void, false)?;
+            }
+            Ok(true)
+        }
+        PollStatus::Locked => {
+            let m: Match = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Match(poll.match_id))
+                .ok_or(PredictXError::MatchNotFound)?;
+            if m.status != MatchStatus::Finished {
+                return Ok(false);
+            }
+
+            // Community votes are Yes/No, so categorical polls go straight
+            // to the oracle's admins.
+            if poll.outcomes.len() > 2 {
+                poll.status = PollStatus::AdminReview;
+                env.events().publish(
+                    (Symbol::new(env, "AdminReviewStarted"), poll.poll_id),
+                    now,
+                );
+                return Ok(true);
+            }
+
+            let oracle = voting_oracle::Client::new(env, &get_oracle(env)?);
+            let tally = oracle.start_voting(&poll.poll_id);
+
+            poll.status = PollStatus::Voting;
+            env.events().publish(
+                (Symbol::new(env, "VotingStarted"), poll.poll_id),
+                tally.voting_end_time,
+            );
+            Ok(true)
+        }
+        PollStatus::Voting => {
+            let oracle = voting_oracle::Client::new(env, &get_oracle(env)?);
+            match oracle.get_consensus(&poll.poll_id) {
+                voting_oracle::Consensus::Pending => Ok(false),
+                voting_oracle::Consensus::AutoResolve(outcome) => {
+                    let winner = PollOutcome::Winner(resolution::binary_index(outcome));
+                    resolution::apply_resolution(env, poll, winner, false)?;
+                    // An auto-resolved vote always has voters on the winning
+                    // side; the oracle pays them from the pool carved here.
+                    let pool = resolution::carve_voter_rewards(env, poll)?;
+                    oracle.record_voter_reward_pool(&poll.poll_id, &pool);
+                    Ok(true)
+                }
+                voting_oracle::Consensus::Void => {
+                    // Voters found the poll unclear: the creator forfeits the bond.
+                    resolution::apply_resolution(env, poll, PollOutcome::Void, true)?;
+                    Ok(true)
+                }
+                voting_oracle::Consensus::NotReached => Err(PredictXError::ConsensusNotReached),
+                voting_oracle::Consensus::AdminReview => {
+                    poll.status = PollStatus::AdminReview;
+                    env.events().publish(
+                        (Symbol::new(env, "VotingClosed"), poll.poll_id),
+                        now,
+                    );
+                    Ok(true)
+                }
+            }
+        }
+        _ => Ok(false),
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        vec, Address, Env, String, Symbol, TryIntoVal,
+    };
+    use predictx_shared::{
+        PollCategory, PollOutcome, PollStatus, PredictXError, Score, VOTING_WINDOW_SECS,
+    };
+    use crate::testutils::TestMarket;
+    use crate::voting_oracle;
+
+    const LOCK_TIME: u64 = 2_000_000;
+    const KICKOFF: u64 = LOCK_TIME + 600;
+    const FULL_TIME: Score = Score { home: 2, away: 1 };
+
+    fn create_poll(s: &TestMarket, match_id: u64) -> u64 {
+        s.create_poll(&s.admin, match_id, PollCategory::PlayerEvent, LOCK_TIME)
+    }
+
+    fn last_event_name(env: &Env) -> Symbol {
+        let (_, topics, _) = env.events().all().last().unwrap();
+        topics.get(0).unwrap().try_into_val(env).unwrap()
+    }
+
+    #[test]
+    fn advance_before_lock_time_is_noop() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Active);
+    }
+
+    #[test]
+    fn advance_locks_poll_at_lock_time() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(LOCK_TIME);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Locked);
+        assert_eq!(last_event_name(&s.env), Symbol::new(&s.env, "PollLocked"));
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::Locked);
+    }
+
+    #[test]
+    fn advance_opens_voting_once_match_finished() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Locked);
+
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Voting);
+        assert_eq!(last_event_name(&s.env), Symbol::new(&s.env, "VotingStarted"));
+
+        let tally = s.oracle().get_voting_stats(&poll_id);
+        assert_eq!(tally.voting_end_time, KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+    }
+
+    #[test]
+    fn oracle_accepts_votes_once_crank_opens_voting() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        // The oracle calls back into this contract's `has_user_staked`.
+        let voter = Address::generate(&s.env);
+        assert!(s.oracle().can_vote(&poll_id, &voter));
+        let tally = s.oracle().cast_vote(&voter, &poll_id, &voting_oracle::VoteChoice::Yes);
+        assert_eq!(tally.yes_votes, 1);
+    }
+
+    #[test]
+    fn advance_applies_every_due_transition_in_one_call() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Voting);
+    }
+
+    #[test]
+    fn advance_closes_voting_at_end_time() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        for i in 0..10 {
+            let choice = if i < 7 { voting_oracle::VoteChoice::Yes } else { voting_oracle::VoteChoice::No };
+            s.oracle().cast_vote(&Address::generate(&s.env), &poll_id, &choice);
+        }
+
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS - 1);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Voting);
+
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::AdminReview);
+    }
+
+    #[test]
+    fn advance_keeps_voting_when_consensus_not_reached() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        for i in 0..10 {
+            let choice = if i < 5 { voting_oracle::VoteChoice::Yes } else { voting_oracle::VoteChoice::No };
+            s.oracle().cast_vote(&Address::generate(&s.env), &poll_id, &choice);
+        }
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+
+        let err = s.client.try_advance_poll(&poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::ConsensusNotReached);
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::Voting);
+
+        s.oracle().admin_verify(&s.admin, &poll_id, &true, &String::from_str(&s.env, "ipfs://r"));
+        assert_eq!(s.client.get_poll(&poll_id).outcome, PollOutcome::Winner(0));
+    }
+
+    #[test]
+    fn advance_resolves_poll_with_auto_consensus() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        for _ in 0..9 {
+            s.oracle().cast_vote(&Address::generate(&s.env), &poll_id, &voting_oracle::VoteChoice::Yes);
+        }
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Resolved);
+        assert_eq!(s.client.get_poll(&poll_id).outcome, PollOutcome::Winner(0));
+    }
+
+    #[test]
+    fn advance_voids_poll_with_unclear_consensus() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        for _ in 0..9 {
+            s.oracle().cast_vote(&Address::generate(&s.env), &poll_id, &voting_oracle::VoteChoice::Unclear);
+        }
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Resolved);
+        assert_eq!(last_event_name(&s.env), Symbol::new(&s.env, "PollVoided"));
+        assert_eq!(s.client.get_poll(&poll_id).outcome, PollOutcome::Void);
+    }
+
+    #[test]
+    fn oracle_finalize_pushes_result_into_market() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        s.client.advance_poll(&poll_id);
+
+        for i in 0..10 {
+            let choice = if i < 7 { voting_oracle::VoteChoice::No } else { voting_oracle::VoteChoice::Yes };
+            s.oracle().cast_vote(&Address::generate(&s.env), &poll_id, &choice);
+        }
+        s.set_time(KICKOFF + 6_000 + VOTING_WINDOW_SECS);
+
+        s.oracle().finalize_voting(&poll_id);
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::AdminReview);
+
+        s.oracle().admin_verify(&s.admin, &poll_id, &false, &String::from_str(&s.env, "ipfs://r"));
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(1));
+    }
+
+    #[test]
+    fn categorical_poll_skips_voting_for_admin_review() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = s.client.create_categorical_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "First scorer?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &vec![
+                &s.env,
+                String::from_str(&s.env, "Saka"),
+                String::from_str(&s.env, "Palmer"),
+                String::from_str(&s.env, "Nobody"),
+            ],
+            &LOCK_TIME,
+        );
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::AdminReview);
+        assert_eq!(last_event_name(&s.env), Symbol::new(&s.env, "AdminReviewStarted"));
+
+        s.oracle().admin_resolve_outcome(&s.admin, &poll_id, &2, &String::from_str(&s.env, "ipfs://r"));
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(2));
+    }
+
+    #[test]
+    fn advance_leaves_resolved_poll_untouched() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let poll_id = create_poll(&s, match_id);
+        s.set_time(LOCK_TIME + 1);
+        s.client.resolve_poll(&poll_id, &true);
+
+        s.set_time(KICKOFF + 6_000);
+        s.client.finish_match(&s.admin, &match_id, &FULL_TIME, &None);
+        assert_eq!(s.client.advance_poll(&poll_id), PollStatus::Resolved);
+    }
+
+    #[test]
+    fn advance_polls_cranks_batch() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.create_match(KICKOFF);
+        let first = create_poll(&s, match_id);
+        let second = create_poll(&s, match_id);
+        s.set_time(LOCK_TIME);
+
+        let statuses = s.client.advance_polls(&vec![&s.env, first, second]);
+        assert_eq!(statuses, vec![&s.env, PollStatus::Locked, PollStatus::Locked]);
+    }
+
+    #[test]
+    fn advance_unknown_poll_fails() {
+        let s = TestMarket::with_oracle();
+        let err = s.client.try_advance_poll(&999u64).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotFound);
+    }
+}
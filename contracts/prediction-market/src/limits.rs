@@ -0,0 +1,329 @@
+use soroban_sdk::{Address, Env, Symbol};
+use predictx_shared::{Poll, PollStatus, PredictXError, MIN_STAKE_AMOUNT};
+use crate::{matches, staking, DataKey, PollStakeLimits, StakeLimits};
+
+// ── Configuration ─────────────────────────────────────────────────────────────
+
+/// Platform-wide stake limits; until the admin sets them, only
+/// `MIN_STAKE_AMOUNT` applies.
+pub fn get_stake_limits(env: &Env) -> StakeLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::StakeLimits)
+        .unwrap_or(StakeLimits {
+            min_stake: MIN_STAKE_AMOUNT,
+            max_stake: 0,
+            max_user_poll_stake: 0,
+            max_user_match_stake: 0,
+            max_poll_pool: 0,
+        })
+}
+
+/// Replace the platform-wide stake limits (admin-only). Polls keep any bounds
+/// their creator set, clamped to the new limits.
+pub fn set_stake_limits(env: &Env, admin: Address, limits: StakeLimits) -> Result<(), PredictXError> {
+    matches::require_admin(env, &admin)?;
+    if limits.min_stake <= 0
+        || limits.max_stake < 0
+        || limits.max_user_poll_stake < 0
+        || limits.max_user_match_stake < 0
+        || limits.max_poll_pool < 0
+        || exceeds(limits.min_stake, limits.max_stake)
+    {
+        return Err(PredictXError::InvalidConfigAmount);
+    }
+
+    env.storage().instance().set(&DataKey::StakeLimits, &limits);
+    env.events()
+        .publish((Symbol::new(env, "StakeLimitsSet"),), limits);
+    Ok(())
+}
+
+/// Set the smallest and largest single stake a poll accepts. Only the poll's
+/// creator may do this, while it is `Active`, and only within the platform
+/// `min_stake`/`max_stake`. A `max_stake` of `0` means no maximum beyond the
+/// platform's.
+pub fn set_poll_stake_limits(
+    env: &Env,
+    creator: Address,
+    poll_id: u64,
+    min_stake: i128,
+    max_stake: i128,
+) -> Result<(), PredictXError> {
+    creator.require_auth();
+
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    if poll.creator != creator {
+        return Err(PredictXError::Unauthorized);
+    }
+    if poll.status != PollStatus::Active {
+        return Err(PredictXError::PollNotActive);
+    }
+
+    let platform = get_stake_limits(env);
+    let above_platform_max = platform.max_stake > 0 && (max_stake == 0 || max_stake > platform.max_stake);
+    if min_stake < platform.min_stake
+        || max_stake < 0
+        || exceeds(min_stake, max_stake)
+        || above_platform_max
+    {
+        return Err(PredictXError::InvalidConfigAmount);
+    }
+
+    let limits = PollStakeLimits { min_stake, max_stake };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PollStakeLimits(poll_id), &limits);
+    env.events().publish(
+        (Symbol::new(env, "PollStakeLimitsSet"), poll_id),
+        (min_stake, max_stake),
+    );
+    Ok(())
+}
+
+/// Single-stake bounds in force on a poll: the creator's, clamped to the
+/// current platform limits, or the platform limits if the creator set none.
+pub fn get_poll_stake_limits(env: &Env, poll_id: u64) -> PollStakeLimits {
+    let platform = get_stake_limits(env);
+    let Some(own) = env
+        .storage()
+        .persistent()
+        .get::<_, PollStakeLimits>(&DataKey::PollStakeLimits(poll_id))
+    else {
+        return PollStakeLimits { min_stake: platform.min_stake, max_stake: platform.max_stake };
+    };
+    let max_stake = match (own.max_stake, platform.max_stake) {
+        (0, platform_max) => platform_max,
+        (own_max, 0) => own_max,
+        (own_max, platform_max) => own_max.min(platform_max),
+    };
+    PollStakeLimits { min_stake: own.min_stake.max(platform.min_stake), max_stake }
+}
+
+/// Total `user` has staked across every poll of a match, net of withdrawals.
+pub fn get_user_match_stake(env: &Env, match_id: u64, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserMatchStake(match_id, user.clone()))
+        .unwrap_or(0)
+}
+
+// ── Enforcement ───────────────────────────────────────────────────────────────
+
+/// Check a new stake of `amount` by `user` against every limit on `poll`.
+pub(crate) fn check_stake(
+    env: &Env,
+    poll: &Poll,
+    user: &Address,
+    amount: i128,
+) -> Result<(), PredictXError> {
+    let bounds = get_poll_stake_limits(env, poll.poll_id);
+    if amount < bounds.min_stake {
+        return Err(PredictXError::StakeBelowMinimum);
+    }
+    if exceeds(amount, bounds.max_stake) {
+        return Err(PredictXError::StakeAboveMaximum);
+    }
+
+    let platform = get_stake_limits(env);
+    let user_poll_stake: i128 = staking::load_positions(env, poll.poll_id, user)
+        .iter()
+        .map(|p| p.amount)
+        .sum();
+    if exceeds(user_poll_stake + amount, platform.max_user_poll_stake) {
+        return Err(PredictXError::UserPollCapExceeded);
+    }
+    if exceeds(get_user_match_stake(env, poll.match_id, user) + amount, platform.max_user_match_stake) {
+        return Err(PredictXError::UserMatchCapExceeded);
+    }
+    if exceeds(staking::total_pool(poll) + amount, platform.max_poll_pool) {
+        return Err(PredictXError::PollPoolCapExceeded);
+    }
+    Ok(())
+}
+
+/// Add `delta` (negative for a withdrawal) to a user's running total on a match.
+pub(crate) fn record_match_stake(env: &Env, match_id: u64, user: &Address, delta: i128) {
+    let total = get_user_match_stake(env, match_id, user) + delta;
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserMatchStake(match_id, user.clone()), &total);
+}
+
+/// Whether `total` is over `cap`, where a cap of `0` means uncapped.
+fn exceeds(total: i128, cap: i128) -> bool {
+    cap > 0 && total > cap
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{testutils::Address as _, Address};
+    use predictx_shared::{PollCategory, PredictXError, StakeSide, MIN_STAKE_AMOUNT};
+    use crate::testutils::TestMarket;
+    use crate::{PollStakeLimits, StakeLimits};
+
+    const KICKOFF: u64 = 2_003_600;
+    const LOCK_TIME: u64 = 2_000_000;
+
+    // ── Helpers ───────────────────────────────────────────────────────────────
+
+    fn create_poll(s: &TestMarket, match_id: u64) -> u64 {
+        s.create_poll(&s.admin, match_id, PollCategory::PlayerEvent, LOCK_TIME)
+    }
+
+    fn funded_user(s: &TestMarket, amount: i128) -> Address {
+        let user = Address::generate(&s.env);
+        s.mint(&user, amount);
+        user
+    }
+
+    fn limits(max_user_poll: i128, max_user_match: i128, max_pool: i128) -> StakeLimits {
+        StakeLimits {
+            min_stake: MIN_STAKE_AMOUNT,
+            max_stake: 0,
+            max_user_poll_stake: max_user_poll,
+            max_user_match_stake: max_user_match,
+            max_poll_pool: max_pool,
+        }
+    }
+
+    // ── Single-stake bounds ───────────────────────────────────────────────────
+
+    #[test]
+    fn poll_bounds_reject_small_and_large_stakes() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s, s.create_match(KICKOFF));
+        s.client.set_poll_stake_limits(&s.admin, &poll_id, &20_000_000, &100_000_000);
+        let user = funded_user(&s, 1_000_000_000);
+
+        let small = s
+            .client
+            .try_stake(&user, &poll_id, &15_000_000, &StakeSide::Yes)
+            .expect_err("below poll minimum");
+        assert_eq!(small, Ok(PredictXError::StakeBelowMinimum));
+        let large = s
+            .client
+            .try_stake(&user, &poll_id, &100_000_001, &StakeSide::Yes)
+            .expect_err("above poll maximum");
+        assert_eq!(large, Ok(PredictXError::StakeAboveMaximum));
+
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+    }
+
+    #[test]
+    fn poll_bounds_must_sit_within_platform_limits() {
+        let s = TestMarket::new();
+        s.client.set_stake_limits(
+            &s.admin,
+            &StakeLimits { max_stake: 500_000_000, ..limits(0, 0, 0) },
+        );
+        let poll_id = create_poll(&s, s.create_match(KICKOFF));
+
+        for (min, max) in [(MIN_STAKE_AMOUNT - 1, 0), (MIN_STAKE_AMOUNT, 0), (MIN_STAKE_AMOUNT, 500_000_001), (60_000_000, 50_000_000)] {
+            let err = s
+                .client
+                .try_set_poll_stake_limits(&s.admin, &poll_id, &min, &max)
+                .expect_err("outside platform limits");
+            assert_eq!(err, Ok(PredictXError::InvalidConfigAmount));
+        }
+        let stranger = s
+            .client
+            .try_set_poll_stake_limits(&Address::generate(&s.env), &poll_id, &MIN_STAKE_AMOUNT, &0)
+            .expect_err("not the creator");
+        assert_eq!(stranger, Ok(PredictXError::Unauthorized));
+
+        s.client.set_poll_stake_limits(&s.admin, &poll_id, &50_000_000, &200_000_000);
+        assert_eq!(
+            s.client.get_poll_stake_limits(&poll_id),
+            PollStakeLimits { min_stake: 50_000_000, max_stake: 200_000_000 }
+        );
+
+        // Tightening the platform maximum clamps the poll's own bound
+        s.client.set_stake_limits(
+            &s.admin,
+            &StakeLimits { max_stake: 100_000_000, ..limits(0, 0, 0) },
+        );
+        assert_eq!(s.client.get_poll_stake_limits(&poll_id).max_stake, 100_000_000);
+    }
+
+    // ── Caps ──────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn user_poll_cap_counts_top_ups() {
+        let s = TestMarket::new();
+        s.client.set_stake_limits(&s.admin, &limits(150_000_000, 0, 0));
+        let poll_id = create_poll(&s, s.create_match(KICKOFF));
+        let user = funded_user(&s, 1_000_000_000);
+
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+        let err = s
+            .client
+            .try_stake(&user, &poll_id, &60_000_000, &StakeSide::Yes)
+            .expect_err("over the per-user poll cap");
+        assert_eq!(err, Ok(PredictXError::UserPollCapExceeded));
+        s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::Yes);
+    }
+
+    #[test]
+    fn user_match_cap_spans_every_poll_of_the_match() {
+        let s = TestMarket::new();
+        s.client.set_stake_limits(&s.admin, &limits(0, 150_000_000, 0));
+        let match_id = s.create_match(KICKOFF);
+        let first = create_poll(&s, match_id);
+        let second = create_poll(&s, match_id);
+        let other_match = create_poll(&s, s.create_match(KICKOFF));
+        let user = funded_user(&s, 1_000_000_000);
+
+        s.client.stake(&user, &first, &100_000_000, &StakeSide::Yes);
+        let err = s
+            .client
+            .try_stake(&user, &second, &60_000_000, &StakeSide::No)
+            .expect_err("over the per-user match cap");
+        assert_eq!(err, Ok(PredictXError::UserMatchCapExceeded));
+        s.client.stake(&user, &other_match, &100_000_000, &StakeSide::No);
+
+        // Withdrawing frees room under the cap
+        s.client.withdraw_stake(&user, &first, &0, &40_000_000);
+        assert_eq!(s.client.get_user_match_stake(&match_id, &user), 60_000_000);
+        s.client.stake(&user, &second, &90_000_000, &StakeSide::No);
+    }
+
+    #[test]
+    fn pool_cap_rejects_a_stake_that_would_overflow_it() {
+        let s = TestMarket::new();
+        s.client.set_stake_limits(&s.admin, &limits(0, 0, 250_000_000));
+        let poll_id = create_poll(&s, s.create_match(KICKOFF));
+
+        s.client.stake(&funded_user(&s, 200_000_000), &poll_id, &200_000_000, &StakeSide::Yes);
+        let err = s
+            .client
+            .try_stake(&funded_user(&s, 60_000_000), &poll_id, &60_000_000, &StakeSide::No)
+            .expect_err("over the pool cap");
+        assert_eq!(err, Ok(PredictXError::PollPoolCapExceeded));
+    }
+
+    #[test]
+    fn set_stake_limits_is_admin_only_and_validated() {
+        let s = TestMarket::new();
+        assert_eq!(s.client.get_stake_limits(), limits(0, 0, 0));
+
+        let stranger = s
+            .client
+            .try_set_stake_limits(&Address::generate(&s.env), &limits(1, 1, 1))
+            .expect_err("not the admin");
+        assert_eq!(stranger, Ok(PredictXError::Unauthorized));
+        let inverted = s
+            .client
+            .try_set_stake_limits(&s.admin, &StakeLimits { max_stake: 1, ..limits(0, 0, 0) })
+            .expect_err("max below min");
+        assert_eq!(inverted, Ok(PredictXError::InvalidConfigAmount));
+    }
+}
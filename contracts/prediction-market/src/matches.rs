@@ -1,6 +1,9 @@
 use soroban_sdk::{Address, Env, String, Symbol, Vec};
-use predictx_shared::{Match, PredictXError};
-use crate::DataKey;   // ← uses prediction-market's local DataKey, not shared one
+use predictx_shared::{
+    Match, MatchEvent, MatchStatus, MatchTransition, Poll, PollStatus, PredictXError, Score,
+    MAX_EVENTS_PER_MATCH,
+};
+use crate::{conditions, lifecycle, DataKey};   // ← uses prediction-market's local DataKey, not shared one
 
 // ── Internal helper ───────────────────────────────────────────────────────────
 
@@ -41,6 +44,8 @@ pub fn create_match(
         .get(&DataKey::NextMatchId)
         .unwrap_or(1);
 
+    let mut transitions = Vec::new(env);
+    transitions.push_back(MatchTransition { status: MatchStatus::Scheduled, at: now });
     let new_match = Match {
         match_id,
         home_team,
@@ -49,7 +54,12 @@ pub fn create_match(
         venue,
         kickoff_time,
         created_by: admin,
-        is_finished: false,
+        status: MatchStatus::Scheduled,
+        home_score: None,
+        away_score: None,
+        half_time_home_score: None,
+        half_time_away_score: None,
+        transitions,
     };
 
     env.storage().persistent().set(&DataKey::Match(match_id), &new_match);
@@ -67,6 +77,9 @@ pub fn create_match(
     Ok(match_id)
 }
 
+/// Edit a fixture before kickoff. Moving the kickoff moves every open poll's
+/// lock time with it.
+#[allow(clippy::too_many_arguments)]
 pub fn update_match(
     env: &Env,
     admin: Address,
@@ -89,17 +102,26 @@ pub fn update_match(
     if now >= m.kickoff_time {
         return Err(PredictXError::MatchAlreadyStarted);
     }
+    match m.status {
+        MatchStatus::Scheduled | MatchStatus::Postponed => {}
+        MatchStatus::Cancelled | MatchStatus::Abandoned => return Err(PredictXError::MatchCancelled),
+        _ => return Err(PredictXError::MatchAlreadyStarted),
+    }
 
     if let Some(v) = home_team  { m.home_team = v; }
     if let Some(v) = away_team  { m.away_team = v; }
     if let Some(v) = league     { m.league    = v; }
     if let Some(v) = venue      { m.venue     = v; }
+    let old_kickoff = m.kickoff_time;
     if let Some(kt) = kickoff_time {
         if kt <= now { return Err(PredictXError::InvalidLockTime); }
         m.kickoff_time = kt;
     }
 
     env.storage().persistent().set(&DataKey::Match(match_id), &m);
+    if m.kickoff_time != old_kickoff {
+        retime_polls(env, match_id, old_kickoff, m.kickoff_time)?;
+    }
 
     env.events().publish(
         (Symbol::new(env, "MatchUpdated"), match_id),
@@ -109,30 +131,302 @@ pub fn update_match(
     Ok(m)
 }
 
+// ── Match lifecycle ───────────────────────────────────────────────────────────
+
+/// Whether `from` → `to` is a legal match transition:
+///
+/// ```text
+/// Scheduled ─┬─► Live ◄──► HalfTime
+/// Postponed ─┤    │           │
+///            │    ├─► Finished │
+///            │    └─► Abandoned ◄┘
+///            ├─► Finished   (after kickoff, if live play was not tracked)
+///            ├─► Postponed
+///            └─► Cancelled
+/// ```
+fn can_transition(from: MatchStatus, to: MatchStatus) -> bool {
+    use MatchStatus::*;
+    matches!(
+        (from, to),
+        (Scheduled | Postponed, Live | Finished | Postponed | Cancelled)
+            | (Live, HalfTime | Finished | Abandoned)
+            | (HalfTime, Live | Abandoned)
+    )
+}
+
+/// Move `m` to `to`, recording when it happened. Kick-off and full-time
+/// transitions are rejected before the scheduled kickoff.
+fn transition(env: &Env, m: &mut Match, to: MatchStatus) -> Result<(), PredictXError> {
+    match m.status {
+        MatchStatus::Cancelled | MatchStatus::Abandoned => return Err(PredictXError::MatchCancelled),
+        MatchStatus::Finished => return Err(PredictXError::MatchAlreadyFinished),
+        _ => {}
+    }
+    if !can_transition(m.status, to) {
+        return Err(PredictXError::InvalidMatchTransition);
+    }
+
+    let now = env.ledger().timestamp();
+    let kicking_off = matches!(m.status, MatchStatus::Scheduled | MatchStatus::Postponed);
+    if kicking_off && matches!(to, MatchStatus::Live | MatchStatus::Finished) && now < m.kickoff_time {
+        return Err(PredictXError::MatchNotStarted);
+    }
+
+    m.status = to;
+    m.transitions.push_back(MatchTransition { status: to, at: now });
+    Ok(())
+}
+
+fn load_match(env: &Env, match_id: u64) -> Result<Match, PredictXError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Match(match_id))
+        .ok_or(PredictXError::MatchNotFound)
+}
+
+/// Apply a transition that carries no extra data and emit `event`.
+fn set_status(
+    env: &Env,
+    admin: Address,
+    match_id: u64,
+    to: MatchStatus,
+    event: &str,
+) -> Result<Match, PredictXError> {
+    require_admin(env, &admin)?;
+
+    let mut m = load_match(env, match_id)?;
+    transition(env, &mut m, to)?;
+    env.storage().persistent().set(&DataKey::Match(match_id), &m);
+
+    env.events().publish(
+        (Symbol::new(env, event), match_id),
+        env.ledger().timestamp(),
+    );
+
+    Ok(m)
+}
+
+/// Kick-off: `Scheduled`/`Postponed` → `Live`.
+pub fn start_match(env: &Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+    set_status(env, admin, match_id, MatchStatus::Live, "MatchStarted")
+}
+
+/// Half-time whistle: `Live` → `HalfTime`.
+pub fn start_half_time(env: &Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+    set_status(env, admin, match_id, MatchStatus::HalfTime, "MatchHalfTime")
+}
+
+/// Second half under way: `HalfTime` → `Live`.
+pub fn resume_match(env: &Env, admin: Address, match_id: u64) -> Result<Match, PredictXError> {
+    set_status(env, admin, match_id, MatchStatus::Live, "MatchResumed")
+}
+
+/// Full-time: record the result and settle every poll whose condition can be
+/// checked against it. The match's other polls can move on to community voting.
 pub fn finish_match(
     env: &Env,
     admin: Address,
     match_id: u64,
+    final_score: Score,
+    half_time_score: Option<Score>,
 ) -> Result<(), PredictXError> {
     require_admin(env, &admin)?;
 
-    let mut m: Match = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Match(match_id))
-        .ok_or(PredictXError::MatchNotFound)?;
+    if let Some(ht) = &half_time_score {
+        if ht.home > final_score.home || ht.away > final_score.away {
+            return Err(PredictXError::InvalidScore);
+        }
+    }
 
-    m.is_finished = true;
+    let mut m = load_match(env, match_id)?;
+    transition(env, &mut m, MatchStatus::Finished)?;
+    m.home_score = Some(final_score.home);
+    m.away_score = Some(final_score.away);
+    m.half_time_home_score = half_time_score.as_ref().map(|ht| ht.home);
+    m.half_time_away_score = half_time_score.as_ref().map(|ht| ht.away);
     env.storage().persistent().set(&DataKey::Match(match_id), &m);
 
     env.events().publish(
         (Symbol::new(env, "MatchFinished"), match_id),
-        (),
+        (final_score.home, final_score.away),
+    );
+
+    conditions::resolve_match_polls(env, &m)
+}
+
+/// Stop a match that kicked off but could not be completed. Its unresolved
+/// polls are cancelled, as with `cancel_match`.
+pub fn abandon_match(
+    env: &Env,
+    admin: Address,
+    match_id: u64,
+    reason: String,
+) -> Result<(), PredictXError> {
+    require_admin(env, &admin)?;
+
+    let mut m = load_match(env, match_id)?;
+    transition(env, &mut m, MatchStatus::Abandoned)?;
+    env.storage().persistent().set(&DataKey::Match(match_id), &m);
+    cancel_polls(env, match_id)?;
+
+    env.events().publish(
+        (Symbol::new(env, "MatchAbandoned"), match_id),
+        reason,
     );
 
     Ok(())
 }
 
+/// Call off a fixture before kickoff and cancel every poll attached to it.
+///
+/// Polls that have not been resolved become `Cancelled`, which makes their
+/// stakes refundable through `claim_refund`.
+pub fn cancel_match(
+    env: &Env,
+    admin: Address,
+    match_id: u64,
+    reason: String,
+) -> Result<(), PredictXError> {
+    require_admin(env, &admin)?;
+
+    let mut m = load_match(env, match_id)?;
+    transition(env, &mut m, MatchStatus::Cancelled)?;
+    env.storage().persistent().set(&DataKey::Match(match_id), &m);
+    cancel_polls(env, match_id)?;
+
+    env.events().publish(
+        (Symbol::new(env, "MatchCancelled"), match_id),
+        reason,
+    );
+
+    Ok(())
+}
+
+fn cancel_polls(env: &Env, match_id: u64) -> Result<(), PredictXError> {
+    for poll_id in get_match_polls(env, match_id)?.iter() {
+        if lifecycle::cancel(env, poll_id, false)? {
+            env.events().publish((Symbol::new(env, "PollCancelled"),), poll_id);
+        }
+    }
+    Ok(())
+}
+
+/// Move a fixture to a later kickoff and shift every open poll's lock time by
+/// the same amount. Polls that already locked stay locked.
+pub fn postpone_match(
+    env: &Env,
+    admin: Address,
+    match_id: u64,
+    new_kickoff: u64,
+) -> Result<Match, PredictXError> {
+    require_admin(env, &admin)?;
+
+    let mut m = load_match(env, match_id)?;
+    let now = env.ledger().timestamp();
+    if new_kickoff <= now || new_kickoff <= m.kickoff_time {
+        return Err(PredictXError::InvalidLockTime);
+    }
+    transition(env, &mut m, MatchStatus::Postponed)?;
+
+    let old_kickoff = m.kickoff_time;
+    m.kickoff_time = new_kickoff;
+    env.storage().persistent().set(&DataKey::Match(match_id), &m);
+    retime_polls(env, match_id, old_kickoff, new_kickoff)?;
+
+    env.events().publish(
+        (Symbol::new(env, "MatchPostponed"), match_id),
+        (old_kickoff, new_kickoff),
+    );
+
+    Ok(m)
+}
+
+/// Move the lock time of every open poll on a match by as much as its
+/// kickoff moved, so each poll keeps locking at the same point of the match.
+/// Locked polls are left alone: their stakes were taken under the old lock.
+fn retime_polls(
+    env: &Env,
+    match_id: u64,
+    old_kickoff: u64,
+    new_kickoff: u64,
+) -> Result<(), PredictXError> {
+    for poll_id in get_match_polls(env, match_id)?.iter() {
+        let mut poll: Poll = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Poll(poll_id))
+            .ok_or(PredictXError::PollNotFound)?;
+        if poll.status != PollStatus::Active {
+            continue;
+        }
+        poll.lock_time = (poll.lock_time + new_kickoff).saturating_sub(old_kickoff);
+        env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+        env.events().publish(
+            (Symbol::new(env, "PollRetimed"), poll_id),
+            poll.lock_time,
+        );
+    }
+    Ok(())
+}
+
+// ── Live event log ────────────────────────────────────────────────────────────
+
+pub fn set_match_feeder(env: &Env, admin: Address, feeder: Address) -> Result<(), PredictXError> {
+    require_admin(env, &admin)?;
+    env.storage().instance().set(&DataKey::MatchFeeder, &feeder);
+    env.events().publish((Symbol::new(env, "MatchFeederSet"),), feeder);
+    Ok(())
+}
+
+/// Append `event` to the log of a match that is in play. Callable by the
+/// admin or the configured match feeder. Returns the number of logged events.
+pub fn record_match_event(
+    env: &Env,
+    caller: Address,
+    match_id: u64,
+    event: MatchEvent,
+) -> Result<u32, PredictXError> {
+    let feeder: Option<Address> = env.storage().instance().get(&DataKey::MatchFeeder);
+    if feeder.as_ref() == Some(&caller) {
+        caller.require_auth();
+    } else {
+        require_admin(env, &caller)?;
+    }
+
+    let m = load_match(env, match_id)?;
+    match m.status {
+        MatchStatus::Live | MatchStatus::HalfTime => {}
+        MatchStatus::Scheduled | MatchStatus::Postponed => return Err(PredictXError::MatchNotStarted),
+        MatchStatus::Finished => return Err(PredictXError::MatchAlreadyFinished),
+        MatchStatus::Cancelled | MatchStatus::Abandoned => return Err(PredictXError::MatchCancelled),
+    }
+
+    let mut events = get_match_events(env, match_id)?;
+    if events.len() >= MAX_EVENTS_PER_MATCH {
+        return Err(PredictXError::MaxMatchEventsReached);
+    }
+    events.push_back(event.clone());
+    env.storage().persistent().set(&DataKey::MatchEvents(match_id), &events);
+
+    env.events().publish(
+        (Symbol::new(env, "MatchEventRecorded"), match_id),
+        event,
+    );
+
+    Ok(events.len())
+}
+
+pub fn get_match_events(env: &Env, match_id: u64) -> Result<Vec<MatchEvent>, PredictXError> {
+    if !env.storage().persistent().has(&DataKey::Match(match_id)) {
+        return Err(PredictXError::MatchNotFound);
+    }
+    Ok(env
+        .storage()
+        .persistent()
+        .get(&DataKey::MatchEvents(match_id))
+        .unwrap_or(Vec::new(env)))
+}
+
 pub fn get_match(env: &Env, match_id: u64) -> Result<Match, PredictXError> {
     env.storage()
         .persistent()
@@ -169,7 +463,9 @@ mod test {
         testutils::{Address as _, Ledger},
         Address, Env, String,
     };
-    use predictx_shared::PredictXError;
+    use predictx_shared::{
+        MatchEvent, MatchEventKind, MatchStatus, PollCategory, PollStatus, PredictXError, Score, Team,
+    };
     use crate::{PredictionMarket, PredictionMarketClient};
 
     // setup now passes a dummy oracle address and token address to match the real initialize signature
@@ -190,6 +486,7 @@ mod test {
     fn s(env: &Env, t: &str) -> String { String::from_str(env, t) }
 
     const KICKOFF: u64 = 1_003_600;
+    const FULL_TIME: Score = Score { home: 2, away: 1 };
 
     fn default_match(env: &Env, client: &PredictionMarketClient, admin: &Address) -> u64 {
         client.create_match(
@@ -213,7 +510,8 @@ mod test {
         let m = client.get_match(&id);
         assert_eq!(m.match_id, 1);
         assert_eq!(m.home_team, s(&env, "Arsenal"));
-        assert!(!m.is_finished);
+        assert_eq!(m.status, MatchStatus::Scheduled);
+        assert_eq!(m.transitions.len(), 1);
     }
 
     #[test]
@@ -305,12 +603,120 @@ mod test {
         assert_eq!(err, PredictXError::Unauthorized);
     }
 
+    fn kick_off(env: &Env) {
+        env.ledger().with_mut(|l| l.timestamp = KICKOFF);
+    }
+
     #[test]
-    fn test_finish_match_sets_flag() {
+    fn test_finish_match_sets_status() {
         let (env, admin, client) = setup();
         let id = default_match(&env, &client, &admin);
-        client.finish_match(&admin, &id);
-        assert!(client.get_match(&id).is_finished);
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+        assert_eq!(client.get_match(&id).status, MatchStatus::Finished);
+    }
+
+    #[test]
+    fn test_finish_match_records_scores() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &Some(Score { home: 1, away: 0 }));
+
+        let m = client.get_match(&id);
+        assert_eq!((m.home_score, m.away_score), (Some(2), Some(1)));
+        assert_eq!((m.half_time_home_score, m.half_time_away_score), (Some(1), Some(0)));
+    }
+
+    #[test]
+    fn test_finish_match_rejects_half_time_above_final() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+        let err = client
+            .try_finish_match(&admin, &id, &FULL_TIME, &Some(Score { home: 0, away: 2 }))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::InvalidScore);
+        assert_eq!(client.get_match(&id).status, MatchStatus::Scheduled);
+    }
+
+    #[test]
+    fn test_finish_match_rejects_before_kickoff() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let err = client.try_finish_match(&admin, &id, &FULL_TIME, &None).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchNotStarted);
+        let err = client.try_start_match(&admin, &id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchNotStarted);
+    }
+
+    #[test]
+    fn test_match_lifecycle_records_transitions() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+
+        assert_eq!(client.start_match(&admin, &id).status, MatchStatus::Live);
+        env.ledger().with_mut(|l| l.timestamp = KICKOFF + 2_700);
+        assert_eq!(client.start_half_time(&admin, &id).status, MatchStatus::HalfTime);
+        env.ledger().with_mut(|l| l.timestamp = KICKOFF + 3_600);
+        assert_eq!(client.resume_match(&admin, &id).status, MatchStatus::Live);
+        env.ledger().with_mut(|l| l.timestamp = KICKOFF + 6_300);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+
+        let m = client.get_match(&id);
+        assert_eq!(m.status, MatchStatus::Finished);
+        let history: std::vec::Vec<(MatchStatus, u64)> =
+            m.transitions.iter().map(|t| (t.status, t.at)).collect();
+        assert_eq!(
+            history,
+            std::vec![
+                (MatchStatus::Scheduled, 1_000_000),
+                (MatchStatus::Live, KICKOFF),
+                (MatchStatus::HalfTime, KICKOFF + 2_700),
+                (MatchStatus::Live, KICKOFF + 3_600),
+                (MatchStatus::Finished, KICKOFF + 6_300),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_match_transitions_rejected() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+
+        let err = client.try_start_half_time(&admin, &id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidMatchTransition);
+        let err = client.try_abandon_match(&admin, &id, &s(&env, "storm")).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidMatchTransition);
+
+        client.start_match(&admin, &id);
+        let err = client.try_resume_match(&admin, &id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidMatchTransition);
+        let err = client.try_cancel_match(&admin, &id, &s(&env, "late")).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidMatchTransition);
+
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+        let err = client.try_start_match(&admin, &id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchAlreadyFinished);
+    }
+
+    #[test]
+    fn test_abandon_match_cancels_attached_polls() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let poll_id = default_poll(&env, &client, &admin, id);
+        kick_off(&env);
+        client.start_match(&admin, &id);
+
+        client.abandon_match(&admin, &id, &s(&env, "Floodlight failure"));
+
+        assert_eq!(client.get_match(&id).status, MatchStatus::Abandoned);
+        assert_eq!(client.get_poll(&poll_id).status, PollStatus::Cancelled);
+        let err = client.try_finish_match(&admin, &id, &FULL_TIME, &None).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchCancelled);
     }
 
     #[test]
@@ -318,7 +724,8 @@ mod test {
         use soroban_sdk::{testutils::Events, Symbol, TryIntoVal};
         let (env, admin, client) = setup();
         let id = default_match(&env, &client, &admin);
-        client.finish_match(&admin, &id);
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
         let events = env.events().all();
         assert_eq!(events.len(), 1);
         let (_, topics, _) = events.get(0).unwrap();
@@ -329,7 +736,7 @@ mod test {
     #[test]
     fn test_finish_nonexistent_match_fails() {
         let (_, admin, client) = setup();
-        let err = client.try_finish_match(&admin, &999u64).unwrap_err().unwrap();
+        let err = client.try_finish_match(&admin, &999u64, &FULL_TIME, &None).unwrap_err().unwrap();
         assert_eq!(err, PredictXError::MatchNotFound);
     }
 
@@ -337,10 +744,58 @@ mod test {
     fn test_finish_match_rejects_non_admin() {
         let (env, admin, client) = setup();
         let id = default_match(&env, &client, &admin);
-        let err = client.try_finish_match(&Address::generate(&env), &id).unwrap_err().unwrap();
+        let err = client.try_finish_match(&Address::generate(&env), &id, &FULL_TIME, &None).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::Unauthorized);
+    }
+
+    fn goal(minute: u32) -> MatchEvent {
+        MatchEvent { minute, kind: MatchEventKind::Goal, team: Team::Home, player_id: 7 }
+    }
+
+    #[test]
+    fn test_record_match_event_appends_to_log() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let feeder = Address::generate(&env);
+        client.set_match_feeder(&admin, &feeder);
+        kick_off(&env);
+        client.start_match(&admin, &id);
+
+        assert_eq!(client.record_match_event(&admin, &id, &goal(10)), 1);
+        assert_eq!(client.record_match_event(&feeder, &id, &goal(60)), 2);
+
+        let events = client.get_match_events(&id);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events.get(0).unwrap(), goal(10));
+        assert_eq!(events.get(1).unwrap(), goal(60));
+    }
+
+    #[test]
+    fn test_record_match_event_rejects_unknown_caller() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+        client.start_match(&admin, &id);
+        let err = client
+            .try_record_match_event(&Address::generate(&env), &id, &goal(10))
+            .unwrap_err()
+            .unwrap();
         assert_eq!(err, PredictXError::Unauthorized);
     }
 
+    #[test]
+    fn test_record_match_event_requires_match_in_play() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let err = client.try_record_match_event(&admin, &id, &goal(1)).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchNotStarted);
+
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+        let err = client.try_record_match_event(&admin, &id, &goal(95)).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchAlreadyFinished);
+    }
+
     #[test]
     fn test_get_match_not_found() {
         let (_, _, client) = setup();
@@ -362,6 +817,152 @@ mod test {
         assert_eq!(err, PredictXError::MatchNotFound);
     }
 
+    fn default_poll(env: &Env, client: &PredictionMarketClient, admin: &Address, match_id: u64) -> u64 {
+        client.create_poll(
+            admin,
+            &match_id,
+            &s(env, "Will Saka score?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &(KICKOFF - 600),
+        )
+    }
+
+    #[test]
+    fn test_cancel_match_cancels_attached_polls() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let first = default_poll(&env, &client, &admin, id);
+        let second = default_poll(&env, &client, &admin, id);
+
+        client.cancel_match(&admin, &id, &s(&env, "Waterlogged pitch"));
+
+        assert_eq!(client.get_match(&id).status, MatchStatus::Cancelled);
+        assert_eq!(client.get_poll(&first).status, PollStatus::Cancelled);
+        assert_eq!(client.get_poll(&second).status, PollStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_cancel_match_blocks_new_polls_and_finish() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        client.cancel_match(&admin, &id, &s(&env, "Called off"));
+
+        let err = client
+            .try_create_poll(&admin, &id, &s(&env, "Q"), &PollCategory::Other, &None, &(KICKOFF - 600))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::MatchCancelled);
+        let err = client.try_finish_match(&admin, &id, &FULL_TIME, &None).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchCancelled);
+        let err = client.try_cancel_match(&admin, &id, &s(&env, "again")).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchCancelled);
+    }
+
+    #[test]
+    fn test_cancel_finished_match_fails() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+        let err = client.try_cancel_match(&admin, &id, &s(&env, "late")).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchAlreadyFinished);
+    }
+
+    #[test]
+    fn test_postpone_match_shifts_poll_lock_times() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let poll_id = default_poll(&env, &client, &admin, id);
+
+        let m = client.postpone_match(&admin, &id, &(KICKOFF + 86_400));
+
+        assert_eq!(m.kickoff_time, KICKOFF + 86_400);
+        let poll = client.get_poll(&poll_id);
+        assert_eq!(poll.lock_time, KICKOFF - 600 + 86_400);
+        assert_eq!(poll.status, PollStatus::Active);
+    }
+
+    #[test]
+    fn test_postpone_match_leaves_locked_polls_locked() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let poll_id = default_poll(&env, &client, &admin, id);
+        env.ledger().with_mut(|l| l.timestamp = KICKOFF - 600);
+        assert_eq!(client.advance_poll(&poll_id), PollStatus::Locked);
+
+        client.postpone_match(&admin, &id, &(KICKOFF + 3_600));
+
+        let poll = client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Locked);
+        assert_eq!(poll.lock_time, KICKOFF - 600);
+    }
+
+    #[test]
+    fn test_postpone_match_rejects_earlier_kickoff() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let err = client.try_postpone_match(&admin, &id, &(KICKOFF - 1)).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidLockTime);
+    }
+
+    #[test]
+    fn test_update_match_moves_poll_lock_times_with_kickoff() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let poll_id = default_poll(&env, &client, &admin, id);
+
+        client.update_match(&admin, &id, &None, &None, &None, &None, &Some(KICKOFF - 1_800));
+        assert_eq!(client.get_poll(&poll_id).lock_time, KICKOFF - 2_400);
+
+        client.update_match(&admin, &id, &None, &None, &None, &None, &Some(KICKOFF + 7_200));
+        assert_eq!(client.get_poll(&poll_id).lock_time, KICKOFF + 6_600);
+    }
+
+    #[test]
+    fn test_create_poll_rejects_lock_long_after_kickoff() {
+        use predictx_shared::MAX_LOCK_AFTER_KICKOFF_SECS;
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let lock_time = KICKOFF + MAX_LOCK_AFTER_KICKOFF_SECS + 1;
+        let err = client.try_create_poll(
+            &admin, &id, &s(&env, "Goal in stoppage time?"),
+            &PollCategory::Other, &None, &lock_time,
+        ).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::InvalidLockTime);
+
+        // In-play polls may still lock after kickoff
+        client.create_poll(
+            &admin, &id, &s(&env, "Goal in the second half?"),
+            &PollCategory::Other, &None, &(lock_time - 1),
+        );
+    }
+
+    #[test]
+    fn test_create_poll_rejects_finished_match() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        kick_off(&env);
+        client.finish_match(&admin, &id, &FULL_TIME, &None);
+        let err = client.try_create_poll(
+            &admin, &id, &s(&env, "Will Saka score?"),
+            &PollCategory::PlayerEvent, &None, &(KICKOFF + 600),
+        ).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::MatchAlreadyFinished);
+    }
+
+    #[test]
+    fn test_create_poll_rejects_long_question() {
+        let (env, admin, client) = setup();
+        let id = default_match(&env, &client, &admin);
+        let question = String::from_bytes(&env, &[b'?'; 257]);
+        let err = client.try_create_poll(
+            &admin, &id, &question,
+            &PollCategory::Other, &None, &(KICKOFF - 600),
+        ).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollQuestionTooLong);
+    }
+
     #[allow(unused_variables)]
     #[test]
     fn test_get_match_count_starts_zero() {
@@ -0,0 +1,403 @@
+use soroban_sdk::{Env, Symbol};
+use predictx_shared::{
+    Poll, PollOutcome, PollStatus, PredictXError, StakeSide, BPS_DENOMINATOR, VOTER_REWARD_BPS,
+};
+use crate::{bonds, DataKey, get_oracle, get_platform_stats, set_platform_stats, staking, token_utils};
+
+// ── Oracle resolution ─────────────────────────────────────────────────────────
+
+/// Record the final outcome of a binary poll. Only the configured
+/// `VotingOracle` may call this; the stored `Poll` is the single source of
+/// truth for "who won".
+///
+/// `outcome`: `true` = Yes won, `false` = No won.
+/// A `Disputed` poll may be resolved again once the oracle's admins have
+/// settled the dispute.
+pub fn resolve_poll(env: &Env, poll_id: u64, outcome: bool) -> Result<(), PredictXError> {
+    if load_poll(env, poll_id)?.outcomes.len() != 2 {
+        return Err(PredictXError::InvalidOutcome);
+    }
+    resolve_poll_outcome(env, poll_id, binary_index(outcome))
+}
+
+/// Record the index of a poll's winning outcome. Only the configured
+/// `VotingOracle` may call this. Same rules as `resolve_poll`.
+pub fn resolve_poll_outcome(env: &Env, poll_id: u64, outcome: u32) -> Result<(), PredictXError> {
+    let oracle = get_oracle(env)?;
+    oracle.require_auth();
+
+    let mut poll = load_poll(env, poll_id)?;
+    if outcome >= poll.outcomes.len() {
+        return Err(PredictXError::InvalidOutcome);
+    }
+    settle(env, &mut poll, PollOutcome::Winner(outcome), false)
+}
+
+/// Settle a poll as `Void`: there is no winner and every stake is refunded in
+/// full through `claim_winnings`, with no platform fee and no voter reward
+/// share. Only the configured `VotingOracle` may call this. Same rules as
+/// `resolve_poll`.
+///
+/// `slash` forfeits the creator's bond; the oracle sets it when voters found
+/// the poll unclear.
+pub fn void_poll(env: &Env, poll_id: u64, slash: bool) -> Result<(), PredictXError> {
+    let oracle = get_oracle(env)?;
+    oracle.require_auth();
+
+    let mut poll = load_poll(env, poll_id)?;
+    settle(env, &mut poll, PollOutcome::Void, slash)
+}
+
+fn settle(env: &Env, poll: &mut Poll, outcome: PollOutcome, slash: bool) -> Result<(), PredictXError> {
+    match poll.status {
+        PollStatus::Resolved => return Err(PredictXError::PollAlreadyResolved),
+        PollStatus::Cancelled => return Err(PredictXError::PollNotActive),
+        PollStatus::Active
+        | PollStatus::Locked
+        | PollStatus::Voting
+        | PollStatus::AdminReview
+        | PollStatus::Disputed => {}
+    }
+
+    if env.ledger().timestamp() < poll.lock_time {
+        return Err(PredictXError::PollNotLocked);
+    }
+
+    apply_resolution(env, poll, outcome, slash)?;
+    if paying_outcome(poll).is_none() {
+        write_off_voter_rewards(env, poll.poll_id);
+    }
+    env.storage().persistent().set(&DataKey::Poll(poll.poll_id), poll);
+    Ok(())
+}
+
+/// Move a poll whose community vote was contested into `AdminReview`.
+/// Only the configured `VotingOracle` may call this.
+pub fn escalate_poll(env: &Env, poll_id: u64) -> Result<(), PredictXError> {
+    let oracle = get_oracle(env)?;
+    oracle.require_auth();
+
+    let mut poll = load_poll(env, poll_id)?;
+    if poll.status != PollStatus::Voting {
+        return Err(PredictXError::VotingNotOpen);
+    }
+
+    poll.status = PollStatus::AdminReview;
+    env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+
+    env.events().publish(
+        (Symbol::new(env, "AdminReviewStarted"), poll_id),
+        env.ledger().timestamp(),
+    );
+    Ok(())
+}
+
+/// Freeze a resolved poll while its outcome is challenged. Only the configured
+/// `VotingOracle` may call this; claims are rejected until the oracle
+/// re-resolves the poll.
+pub fn mark_disputed(env: &Env, poll_id: u64) -> Result<(), PredictXError> {
+    let oracle = get_oracle(env)?;
+    oracle.require_auth();
+
+    let mut poll = load_poll(env, poll_id)?;
+    if poll.status != PollStatus::Resolved {
+        return Err(PredictXError::PollNotResolved);
+    }
+
+    poll.status = PollStatus::Disputed;
+    env.storage().persistent().set(&DataKey::Poll(poll_id), &poll);
+
+    env.events().publish(
+        (Symbol::new(env, "PollDisputed"), poll_id),
+        env.ledger().timestamp(),
+    );
+    Ok(())
+}
+
+// ── Voter rewards ─────────────────────────────────────────────────────────────
+
+/// Carve the voter reward share out of a resolved poll. Only the configured
+/// `VotingOracle` may call this, once its vote tally shows at least one voter
+/// backed the final outcome.
+///
+/// Returns the amount held for voters; repeated calls return the same amount
+/// without moving funds again.
+pub fn fund_voter_rewards(env: &Env, poll_id: u64) -> Result<i128, PredictXError> {
+    let oracle = get_oracle(env)?;
+    oracle.require_auth();
+
+    let poll = load_poll(env, poll_id)?;
+    if poll.status != PollStatus::Resolved {
+        return Err(PredictXError::PollNotResolved);
+    }
+    carve_voter_rewards(env, &poll)
+}
+
+/// Amount carved from a poll's pool for voter rewards (zero if none).
+pub fn get_voter_reward_pool(env: &Env, poll_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoterRewardPool(poll_id))
+        .unwrap_or(0)
+}
+
+/// Move `VOTER_REWARD_BPS` of the poll's total pool to the `VotingOracle`,
+/// which pays it out to voters. Winners share what remains, so this must run
+/// before the first claim; it is a no-op once a poll has been carved. Polls
+/// that refund every stake are never carved.
+pub(crate) fn carve_voter_rewards(env: &Env, poll: &Poll) -> Result<i128, PredictXError> {
+    if paying_outcome(poll).is_none() {
+        return Ok(0);
+    }
+    let key = DataKey::VoterRewardPool(poll.poll_id);
+    if let Some(amount) = env.storage().persistent().get(&key) {
+        return Ok(amount);
+    }
+
+    let total_pool = staking::total_pool(poll);
+    let amount = total_pool * VOTER_REWARD_BPS as i128 / BPS_DENOMINATOR as i128;
+    env.storage().persistent().set(&key, &amount);
+
+    if amount > 0 {
+        let mut stats = get_platform_stats(env);
+        stats.total_value_locked -= amount;
+        set_platform_stats(env, &stats);
+
+        token_utils::transfer_from_contract(env, &get_oracle(env)?, amount)?;
+
+        env.events().publish(
+            (Symbol::new(env, "VoterRewardsFunded"), poll.poll_id),
+            amount,
+        );
+    }
+    Ok(amount)
+}
+
+/// Drop the voter reward pool of a poll that now refunds every stake, which
+/// happens when a dispute re-resolves a carved poll. The oracle sends the
+/// carved tokens back straight after re-resolving it.
+fn write_off_voter_rewards(env: &Env, poll_id: u64) {
+    let key = DataKey::VoterRewardPool(poll_id);
+    let Some(amount) = env.storage().persistent().get::<_, i128>(&key) else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+
+    let mut stats = get_platform_stats(env);
+    stats.total_value_locked += amount;
+    set_platform_stats(env, &stats);
+}
+
+/// Outcome index of a Yes/No result on a binary poll.
+pub(crate) fn binary_index(yes: bool) -> u32 {
+    if yes { StakeSide::Yes as u32 } else { StakeSide::No as u32 }
+}
+
+/// Whether every staker on `poll` backed the same outcome. Nobody took the
+/// other side, so there is no one to pay winners and the poll is voided.
+pub(crate) fn is_one_sided(poll: &Poll) -> bool {
+    poll.outcome_counts.iter().filter(|count| *count > 0).count() == 1
+}
+
+/// Winning outcome of a resolved poll if its winners are paid out, or `None`
+/// when every stake is simply refunded: the poll was voided, or nobody backed
+/// the winning outcome.
+pub(crate) fn paying_outcome(poll: &Poll) -> Option<u32> {
+    match poll.outcome {
+        PollOutcome::Winner(index) if poll.outcome_pools.get_unchecked(index) > 0 => Some(index),
+        _ => None,
+    }
+}
+
+/// Write `outcome` onto `poll` and emit `PollResolved` (or `PollVoided`).
+/// A one-sided poll is always voided. The caller persists it.
+///
+/// The creator's bond is slashed if `slash` is set (voters found the poll
+/// unclear) and returned otherwise; a one-sided poll is not the creator's
+/// fault.
+pub(crate) fn apply_resolution(
+    env: &Env,
+    poll: &mut Poll,
+    outcome: PollOutcome,
+    slash: bool,
+) -> Result<(), PredictXError> {
+    if is_one_sided(poll) {
+        bonds::release(env, poll)?;
+        poll.outcome = PollOutcome::Void;
+    } else {
+        if slash {
+            bonds::slash(env, poll)?;
+        } else {
+            bonds::release(env, poll)?;
+        }
+        poll.outcome = outcome;
+    }
+    let outcome = poll.outcome;
+    poll.status = PollStatus::Resolved;
+    poll.resolution_time = env.ledger().timestamp();
+
+    match outcome {
+        PollOutcome::Winner(index) => env.events().publish(
+            (Symbol::new(env, "PollResolved"), poll.poll_id),
+            index,
+        ),
+        _ => env.events().publish(
+            (Symbol::new(env, "PollVoided"), poll.poll_id),
+            poll.resolution_time,
+        ),
+    }
+    Ok(())
+}
+
+fn load_poll(env: &Env, poll_id: u64) -> Result<Poll, PredictXError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{testutils::Events, Symbol, TryIntoVal};
+    use predictx_shared::{PollCategory, PollOutcome, PollStatus, PredictXError};
+    use crate::testutils::TestMarket;
+
+    const LOCK_TIME: u64 = 2_000_000;
+
+    fn create_poll(s: &TestMarket) -> u64 {
+        let match_id = s.create_match(LOCK_TIME + 3600);
+        s.create_poll(&s.admin, match_id, PollCategory::PlayerEvent, LOCK_TIME)
+    }
+
+    #[test]
+    fn resolve_poll_writes_outcome_and_status() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        s.client.resolve_poll(&poll_id, &true);
+
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(0));
+        assert_eq!(poll.resolution_time, LOCK_TIME + 10);
+    }
+
+    #[test]
+    fn resolve_poll_requires_oracle_auth() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        s.client.resolve_poll(&poll_id, &false);
+        let auths = s.env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, s.oracle_id);
+    }
+
+    #[test]
+    fn resolve_poll_emits_event() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        s.client.resolve_poll(&poll_id, &true);
+
+        let events = s.env.events().all();
+        let (_, topics, _) = events.last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&s.env).unwrap();
+        assert_eq!(name, Symbol::new(&s.env, "PollResolved"));
+    }
+
+    #[test]
+    fn resolve_poll_rejects_before_lock_time() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        let err = s.client.try_resolve_poll(&poll_id, &true).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotLocked);
+    }
+
+    #[test]
+    fn resolve_poll_rejects_second_resolution() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        s.client.resolve_poll(&poll_id, &true);
+        let err = s.client.try_resolve_poll(&poll_id, &false).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollAlreadyResolved);
+    }
+
+    #[test]
+    fn void_poll_settles_without_winner() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        s.client.void_poll(&poll_id, &false);
+        assert_eq!(s.env.auths()[0].0, s.oracle_id);
+        let (_, topics, _) = s.env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&s.env).unwrap();
+        assert_eq!(name, Symbol::new(&s.env, "PollVoided"));
+
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Void);
+        assert_eq!(s.client.fund_voter_rewards(&poll_id), 0);
+
+        let err = s.client.try_resolve_poll(&poll_id, &true).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollAlreadyResolved);
+    }
+
+    #[test]
+    fn escalate_poll_requires_voting_status() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        let err = s.client.try_escalate_poll(&poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::VotingNotOpen);
+    }
+
+    #[test]
+    fn mark_disputed_freezes_resolved_poll() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+
+        let err = s.client.try_mark_disputed(&poll_id).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotResolved);
+
+        s.client.resolve_poll(&poll_id, &true);
+        s.client.mark_disputed(&poll_id);
+        assert_eq!(s.client.get_poll(&poll_id).status, PollStatus::Disputed);
+    }
+
+    #[test]
+    fn disputed_poll_can_be_re_resolved() {
+        let s = TestMarket::new();
+        let poll_id = create_poll(&s);
+        s.set_time(LOCK_TIME + 10);
+        s.client.resolve_poll(&poll_id, &true);
+        s.client.mark_disputed(&poll_id);
+
+        s.set_time(LOCK_TIME + 500);
+        s.client.resolve_poll(&poll_id, &false);
+
+        let poll = s.client.get_poll(&poll_id);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(1));
+        assert_eq!(poll.resolution_time, LOCK_TIME + 500);
+    }
+
+    #[test]
+    fn resolve_poll_unknown_poll_fails() {
+        let s = TestMarket::new();
+        let err = s.client.try_resolve_poll(&999u64, &true).unwrap_err().unwrap();
+        assert_eq!(err, PredictXError::PollNotFound);
+    }
+}
@@ -1,24 +1,38 @@
 use soroban_sdk::{Address, Env, Symbol, Vec};
 use predictx_shared::{
-    Poll, PollStatus, Stake, StakeSide, PredictXError,
-    MIN_STAKE_AMOUNT, BPS_DENOMINATOR,
+    FeeSource, Poll, PollStatus, Stake, StakeEntry, PredictXError,
+    BPS_DENOMINATOR,
 };
-use crate::{DataKey, PoolInfo, get_platform_stats, set_platform_stats, ensure_not_paused, token_utils};
+use crate::{limits, DataKey, PoolInfo, get_platform_stats, set_platform_stats, ensure_not_paused, token_utils};
 
 // ── Stake placement ───────────────────────────────────────────────────────────
 
-/// Place a stake on a poll outcome. Follows Checks-Effects-Interactions pattern.
+/// Place a stake on outcome `outcome` of a poll. Binary polls use index 0 for
+/// Yes and 1 for No. Follows Checks-Effects-Interactions pattern.
+///
+/// Staking again on an outcome the user already backs tops up that position.
+/// Staking on a different outcome is rejected with `AlreadyStaked` unless the
+/// poll's creator has enabled hedging, in which case it opens a separate
+/// position. Every placement is appended to the user's stake history, and
+/// must fit the poll's stake limits (see `limits::check_stake`).
+///
+/// With `min_payout` set, the stake is rejected with `PayoutBelowMinimum` if
+/// the resulting position would pay less than that should its outcome win,
+/// judged on the pools as they stand including this stake.
 ///
 /// 1. Validates all preconditions (checks)
 /// 2. Transfers tokens from staker to contract (interactions — first because
 ///    Soroban token transfers are safe against re-entrancy)
 /// 3. Records state changes (effects)
+///
+/// Returns the position staked on, after this placement.
 pub fn stake(
     env: &Env,
     staker: Address,
     poll_id: u64,
+    outcome: u32,
     amount: i128,
-    side: StakeSide,
+    min_payout: Option<i128>,
 ) -> Result<Stake, PredictXError> {
     staker.require_auth();
     ensure_not_paused(env)?;
@@ -28,9 +42,6 @@ pub fn stake(
     if amount <= 0 {
         return Err(PredictXError::StakeAmountZero);
     }
-    if amount < MIN_STAKE_AMOUNT {
-        return Err(PredictXError::StakeBelowMinimum);
-    }
 
     let mut poll: Poll = env
         .storage()
@@ -46,12 +57,38 @@ pub fn stake(
         return Err(PredictXError::PollLocked);
     }
 
-    if env
+    if outcome >= poll.outcomes.len() {
+        return Err(PredictXError::InvalidOutcome);
+    }
+
+    limits::check_stake(env, &poll, &staker, amount)?;
+
+    let primary: Option<Stake> = env
         .storage()
         .persistent()
-        .has(&DataKey::HasStaked(poll_id, staker.clone()))
-    {
-        return Err(PredictXError::AlreadyStaked);
+        .get(&DataKey::Stake(poll_id, staker.clone()));
+    let key = match &primary {
+        Some(p) if p.outcome != outcome => {
+            if !is_hedging_enabled(env, poll_id) {
+                return Err(PredictXError::AlreadyStaked);
+            }
+            DataKey::HedgeStake(poll_id, staker.clone(), outcome)
+        }
+        _ => DataKey::Stake(poll_id, staker.clone()),
+    };
+    let existing: Option<Stake> = env.storage().persistent().get(&key);
+
+    if let Some(min_payout) = min_payout {
+        let position = existing.as_ref().map_or(0, |p| p.amount) + amount;
+        let projected = net_payout(
+            position,
+            poll.outcome_pools.get_unchecked(outcome) + amount,
+            total_pool(&poll) + amount,
+            token_utils::get_payout_fee_bps(env),
+        );
+        if projected < min_payout {
+            return Err(PredictXError::PayoutBelowMinimum);
+        }
     }
 
     // ── Interactions ──────────────────────────────────────────────────────────
@@ -60,62 +97,306 @@ pub fn stake(
 
     // ── Effects ───────────────────────────────────────────────────────────────
 
-    let stake_record = Stake {
-        user: staker.clone(),
-        poll_id,
-        amount,
-        side,
-        claimed: false,
-        staked_at: env.ledger().timestamp(),
+    let now = env.ledger().timestamp();
+    let is_new_position = existing.is_none();
+    let stake_record = match existing {
+        Some(mut position) => {
+            position.amount += amount;
+            position
+        }
+        None => Stake {
+            user: staker.clone(),
+            poll_id,
+            amount,
+            outcome,
+            claimed: false,
+            staked_at: now,
+        },
     };
+    env.storage().persistent().set(&key, &stake_record);
 
-    // Store stake record + flag
+    let mut history = get_stake_history(env, poll_id, &staker);
+    history.push_back(StakeEntry { outcome, amount, at: now });
     env.storage()
         .persistent()
-        .set(&DataKey::Stake(poll_id, staker.clone()), &stake_record);
+        .set(&DataKey::StakeHistory(poll_id, staker.clone()), &history);
+
+    // Update pool totals; counts are unique stakers per outcome
+    let pool = poll.outcome_pools.get_unchecked(outcome);
+    poll.outcome_pools.set(outcome, pool + amount);
+    if is_new_position {
+        let count = poll.outcome_counts.get_unchecked(outcome);
+        poll.outcome_counts.set(outcome, count + 1);
+    }
     env.storage()
         .persistent()
-        .set(&DataKey::HasStaked(poll_id, staker.clone()), &true);
+        .set(&DataKey::Poll(poll_id), &poll);
+    limits::record_match_stake(env, poll.match_id, &staker, amount);
+
+    if primary.is_none() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::HasStaked(poll_id, staker.clone()), &true);
+
+        // Track user's staked polls
+        let mut user_stakes: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserStakes(staker.clone()))
+            .unwrap_or(Vec::new(env));
+        user_stakes.push_back(poll_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserStakes(staker.clone()), &user_stakes);
+    }
 
-    // Update pool totals
-    match side {
-        StakeSide::Yes => {
-            poll.yes_pool += amount;
-            poll.yes_count += 1;
-        }
-        StakeSide::No => {
-            poll.no_pool += amount;
-            poll.no_count += 1;
+    // Update platform stats
+    let mut stats = get_platform_stats(env);
+    stats.total_value_locked += amount;
+    stats.total_stakes_placed += 1;
+    set_platform_stats(env, &stats);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "StakePlaced"), poll_id, staker),
+        (amount, outcome),
+    );
+
+    Ok(stake_record)
+}
+
+/// Take `amount` back out of a user's position on `outcome` while the poll is
+/// still `Active` and unlocked. The exit fee (`get_exit_fee_bps`) goes to the
+/// treasury and the rest to the user; what remains staked must be zero or at
+/// least the poll's minimum stake.
+///
+/// Withdrawing a position in full stops counting the user on that outcome; if
+/// it was their first position and they hedged, their oldest hedge takes its
+/// place. Withdrawing their last position forgets that they staked on the poll
+/// at all.
+///
+/// Returns the amount paid to the user, after the fee.
+pub fn withdraw_stake(
+    env: &Env,
+    user: Address,
+    poll_id: u64,
+    outcome: u32,
+    amount: i128,
+) -> Result<i128, PredictXError> {
+    user.require_auth();
+    ensure_not_paused(env)?;
+
+    // ── Checks ────────────────────────────────────────────────────────────────
+
+    if amount <= 0 {
+        return Err(PredictXError::StakeAmountZero);
+    }
+
+    let mut poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    if poll.status != PollStatus::Active {
+        return Err(PredictXError::PollNotActive);
+    }
+    if env.ledger().timestamp() >= poll.lock_time {
+        return Err(PredictXError::PollLocked);
+    }
+
+    let positions = load_positions(env, poll_id, &user);
+    let index = positions
+        .iter()
+        .position(|p| p.outcome == outcome)
+        .ok_or(PredictXError::NotStaker)?;
+    let mut position = positions.get_unchecked(index as u32);
+    if amount > position.amount {
+        return Err(PredictXError::InsufficientBalance);
+    }
+    let remaining = position.amount - amount;
+    if remaining > 0 && remaining < limits::get_poll_stake_limits(env, poll_id).min_stake {
+        return Err(PredictXError::StakeBelowMinimum);
+    }
+
+    let fee = amount * token_utils::get_exit_fee_bps(env) as i128 / BPS_DENOMINATOR as i128;
+    let payout = amount - fee;
+
+    // ── Effects ───────────────────────────────────────────────────────────────
+
+    let pool = poll.outcome_pools.get_unchecked(outcome);
+    poll.outcome_pools.set(outcome, pool - amount);
+
+    let primary_key = DataKey::Stake(poll_id, user.clone());
+    if remaining > 0 {
+        position.amount = remaining;
+        save_position(env, &position);
+    } else if index > 0 {
+        let count = poll.outcome_counts.get_unchecked(outcome);
+        poll.outcome_counts.set(outcome, count - 1);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HedgeStake(poll_id, user.clone(), outcome));
+    } else {
+        let count = poll.outcome_counts.get_unchecked(outcome);
+        poll.outcome_counts.set(outcome, count - 1);
+        match positions.get(1) {
+            Some(hedge) => {
+                env.storage().persistent().remove(&DataKey::HedgeStake(
+                    poll_id,
+                    user.clone(),
+                    hedge.outcome,
+                ));
+                env.storage().persistent().set(&primary_key, &hedge);
+            }
+            None => {
+                env.storage().persistent().remove(&primary_key);
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::HasStaked(poll_id, user.clone()));
+                let mut user_stakes = get_user_stakes(env, &user);
+                if let Some(i) = user_stakes.first_index_of(poll_id) {
+                    user_stakes.remove(i);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::UserStakes(user.clone()), &user_stakes);
+            }
         }
     }
     env.storage()
         .persistent()
         .set(&DataKey::Poll(poll_id), &poll);
+    limits::record_match_stake(env, poll.match_id, &user, -amount);
 
-    // Track user's staked polls
-    let mut user_stakes: Vec<u64> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::UserStakes(staker.clone()))
-        .unwrap_or(Vec::new(env));
-    user_stakes.push_back(poll_id);
+    let mut history = get_stake_history(env, poll_id, &user);
+    history.push_back(StakeEntry { outcome, amount: -amount, at: env.ledger().timestamp() });
     env.storage()
         .persistent()
-        .set(&DataKey::UserStakes(staker.clone()), &user_stakes);
+        .set(&DataKey::StakeHistory(poll_id, user.clone()), &history);
 
-    // Update platform stats
     let mut stats = get_platform_stats(env);
-    stats.total_value_locked += amount;
-    stats.total_stakes_placed += 1;
+    stats.total_value_locked -= amount;
     set_platform_stats(env, &stats);
 
-    // Emit event
+    // ── Interactions ──────────────────────────────────────────────────────────
+
+    token_utils::transfer_from_contract(env, &user, payout)?;
+    if fee > 0 {
+        token_utils::transfer_to_treasury(env, fee, FeeSource::ExitFee)?;
+    }
+
     env.events().publish(
-        (Symbol::new(env, "StakePlaced"), poll_id, staker),
-        (amount, side),
+        (Symbol::new(env, "StakeWithdrawn"), poll_id, user),
+        (amount, fee),
     );
 
-    Ok(stake_record)
+    Ok(payout)
+}
+
+/// Allow or forbid stakers on a poll to back more than one outcome. Only the
+/// poll's creator may change this, and only while the poll is `Active`.
+pub fn set_poll_hedging(
+    env: &Env,
+    creator: Address,
+    poll_id: u64,
+    enabled: bool,
+) -> Result<(), PredictXError> {
+    creator.require_auth();
+
+    let poll: Poll = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Poll(poll_id))
+        .ok_or(PredictXError::PollNotFound)?;
+    if poll.creator != creator {
+        return Err(PredictXError::Unauthorized);
+    }
+    if poll.status != PollStatus::Active {
+        return Err(PredictXError::PollNotActive);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::HedgingEnabled(poll_id), &enabled);
+    env.events().publish(
+        (Symbol::new(env, "PollHedgingSet"), poll_id),
+        enabled,
+    );
+    Ok(())
+}
+
+pub fn is_hedging_enabled(env: &Env, poll_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HedgingEnabled(poll_id))
+        .unwrap_or(false)
+}
+
+// ── Positions ─────────────────────────────────────────────────────────────────
+
+/// Every position `user` holds on a poll: their first stake, then any hedges
+/// on other outcomes in the order they were opened.
+pub(crate) fn load_positions(env: &Env, poll_id: u64, user: &Address) -> Vec<Stake> {
+    let mut positions: Vec<Stake> = Vec::new(env);
+    let Some(primary) = env
+        .storage()
+        .persistent()
+        .get::<_, Stake>(&DataKey::Stake(poll_id, user.clone()))
+    else {
+        return positions;
+    };
+    positions.push_back(primary);
+    // Hedges can only exist on outcomes the user's history shows a stake on.
+    for entry in get_stake_history(env, poll_id, user).iter() {
+        if positions.iter().any(|p| p.outcome == entry.outcome) {
+            continue;
+        }
+        let key = DataKey::HedgeStake(poll_id, user.clone(), entry.outcome);
+        if let Some(hedge) = env.storage().persistent().get(&key) {
+            positions.push_back(hedge);
+        }
+    }
+    positions
+}
+
+/// Persist a position loaded with `load_positions`.
+pub(crate) fn save_position(env: &Env, position: &Stake) {
+    let key = DataKey::Stake(position.poll_id, position.user.clone());
+    let is_primary = env
+        .storage()
+        .persistent()
+        .get::<_, Stake>(&key)
+        .is_some_and(|primary| primary.outcome == position.outcome);
+    if is_primary {
+        env.storage().persistent().set(&key, position);
+    } else {
+        env.storage().persistent().set(
+            &DataKey::HedgeStake(position.poll_id, position.user.clone(), position.outcome),
+            position,
+        );
+    }
+}
+
+/// Mark every unclaimed position of `user` on a poll as claimed and return
+/// their combined amount, for full refunds.
+pub(crate) fn take_refund(env: &Env, poll_id: u64, user: &Address) -> Result<i128, PredictXError> {
+    let positions = load_positions(env, poll_id, user);
+    if positions.is_empty() {
+        return Err(PredictXError::NotStaker);
+    }
+    let mut total = 0;
+    for mut position in positions.iter() {
+        if position.claimed {
+            continue;
+        }
+        total += position.amount;
+        position.claimed = true;
+        save_position(env, &position);
+    }
+    if total == 0 {
+        return Err(PredictXError::AlreadyClaimed);
+    }
+    Ok(total)
 }
 
 // ── View functions ────────────────────────────────────────────────────────────
@@ -136,6 +417,19 @@ pub fn get_user_stakes(env: &Env, user: &Address) -> Vec<u64> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Every position a user holds on a poll; more than one only if they hedged.
+pub fn get_user_positions(env: &Env, poll_id: u64, user: &Address) -> Vec<Stake> {
+    load_positions(env, poll_id, user)
+}
+
+/// Every stake placement a user made on a poll, oldest first.
+pub fn get_stake_history(env: &Env, poll_id: u64, user: &Address) -> Vec<StakeEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeHistory(poll_id, user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 /// Check whether a user has already staked on a given poll.
 pub fn has_user_staked(env: &Env, poll_id: u64, user: &Address) -> bool {
     env.storage()
@@ -147,16 +441,17 @@ pub fn has_user_staked(env: &Env, poll_id: u64, user: &Address) -> bool {
 ///
 /// Formula (integer arithmetic, all in base token units):
 /// ```text
-/// pool_on_side_after  = pool_on_side + amount
-/// total_pool_after    = yes_pool + no_pool + amount
-/// winnings = amount * total_pool_after * (BPS_DENOMINATOR - PLATFORM_FEE_BPS)
+/// pool_on_side_after  = outcome_pools[outcome] + amount
+/// total_pool_after    = sum(outcome_pools) + amount
+/// winnings = amount * total_pool_after * (BPS_DENOMINATOR - fee_bps)
 ///            / (pool_on_side_after * BPS_DENOMINATOR)
 /// ```
+/// where `fee_bps` is the platform fee plus the creator fee.
 /// Integer division rounds down — dust stays in contract (benefits the platform).
 pub fn calculate_potential_winnings(
     env: &Env,
     poll_id: u64,
-    side: StakeSide,
+    outcome: u32,
     amount: i128,
 ) -> Result<i128, PredictXError> {
     if amount <= 0 {
@@ -169,22 +464,34 @@ pub fn calculate_potential_winnings(
         .get(&DataKey::Poll(poll_id))
         .ok_or(PredictXError::PollNotFound)?;
 
-    let pool_on_side = match side {
-        StakeSide::Yes => poll.yes_pool,
-        StakeSide::No => poll.no_pool,
-    };
+    let pool_on_side = poll
+        .outcome_pools
+        .get(outcome)
+        .ok_or(PredictXError::InvalidOutcome)?;
 
     let pool_on_side_after = pool_on_side + amount;
-    let total_pool_after = poll.yes_pool + poll.no_pool + amount;
+    let total_pool_after = total_pool(&poll) + amount;
+
+    let fee_bps = token_utils::get_payout_fee_bps(env);
+    Ok(net_payout(amount, pool_on_side_after, total_pool_after, fee_bps))
+}
 
-    let fee_bps = token_utils::get_platform_fee_bps(env);
+/// Pari-mutuel payout for `amount` staked on a side holding `side_pool`, out of
+/// a `total_pool`, after deducting `fee_bps`.
+///
+/// Shared by the pre-stake preview and `claims::claim_winnings` so the number a
+/// user is quoted is computed exactly like the number they are paid.
+pub(crate) fn net_payout(amount: i128, side_pool: i128, total_pool: i128, fee_bps: u32) -> i128 {
     let fee_factor = (BPS_DENOMINATOR - fee_bps) as i128;
     let bps = BPS_DENOMINATOR as i128;
 
-    // winnings = (amount / pool_on_side_after) * total_pool_after * (1 - fee%)
-    let winnings = amount * total_pool_after * fee_factor / (pool_on_side_after * bps);
+    // winnings = (amount / side_pool) * total_pool * (1 - fee%)
+    amount * total_pool * fee_factor / (side_pool * bps)
+}
 
-    Ok(winnings)
+/// Sum of every outcome pool of a poll.
+pub(crate) fn total_pool(poll: &Poll) -> i128 {
+    poll.outcome_pools.iter().sum()
 }
 
 /// Return pool state for a poll.
@@ -196,10 +503,8 @@ pub fn get_pool_info(env: &Env, poll_id: u64) -> Result<PoolInfo, PredictXError>
         .ok_or(PredictXError::PollNotFound)?;
 
     Ok(PoolInfo {
-        yes_pool: poll.yes_pool,
-        no_pool: poll.no_pool,
-        yes_count: poll.yes_count,
-        no_count: poll.no_count,
+        outcome_pools: poll.outcome_pools,
+        outcome_counts: poll.outcome_counts,
     })
 }
 
@@ -211,53 +516,18 @@ mod test {
 
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
-        token, Address, Env, String,
+        Address, String,
+    };
+    use predictx_shared::{
+        FeeSource, PollCategory, PollStatus, PredictXError, StakeEntry, StakeSide, Poll,
     };
-    use predictx_shared::{PollCategory, PollStatus, PredictXError, StakeSide, Poll};
-    use crate::{DataKey, PredictionMarket, PredictionMarketClient};
+    use crate::testutils::TestMarket;
+    use crate::DataKey;
 
     // ── Helpers ───────────────────────────────────────────────────────────────
 
-    struct TestSetup<'a> {
-        env: Env,
-        admin: Address,
-        #[allow(dead_code)]
-        oracle_id: Address,
-        token_addr: Address,
-        contract_id: Address,
-        client: PredictionMarketClient<'a>,
-    }
-
-    fn setup() -> TestSetup<'static> {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-
-        // Register voting oracle
-        let oracle_id = env.register(crate::voting_oracle::WASM, ());
-        let oracle_client = crate::voting_oracle::Client::new(&env, &oracle_id);
-        oracle_client.initialize(&admin);
-
-        // Register a Stellar-asset token for staking
-        let token_admin = Address::generate(&env);
-        let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
-        let token_addr = token_contract.address();
-
-        // Register prediction market and initialize
-        let contract_id = env.register(PredictionMarket, ());
-        let client = PredictionMarketClient::new(&env, &contract_id);
-        let treasury = Address::generate(&env);
-        client.initialize(&admin, &oracle_id, &token_addr, &treasury, &500_u32);
-
-        // Set ledger timestamp
-        env.ledger().with_mut(|l| l.timestamp = 1_000_000);
-
-        TestSetup { env, admin, oracle_id, token_addr, contract_id, client }
-    }
-
     /// Create a test match + poll with the given lock_time.  Returns poll_id.
-    fn create_test_poll(s: &TestSetup, lock_time: u64) -> u64 {
+    fn create_test_poll(s: &TestMarket, lock_time: u64) -> u64 {
         let match_id = s.client.create_match(
             &s.admin,
             &String::from_str(&s.env, "Arsenal"),
@@ -271,49 +541,41 @@ mod test {
             &match_id,
             &String::from_str(&s.env, "Will Palmer score?"),
             &PollCategory::PlayerEvent,
+            &None,
             &lock_time,
         )
     }
 
-    fn mint_tokens(s: &TestSetup, to: &Address, amount: i128) {
-        let sac = token::StellarAssetClient::new(&s.env, &s.token_addr);
-        sac.mint(to, &amount);
-    }
-
-    fn token_balance(s: &TestSetup, addr: &Address) -> i128 {
-        token::Client::new(&s.env, &s.token_addr).balance(addr)
-    }
-
     // ── Stake placement ───────────────────────────────────────────────────────
 
     #[test]
     fn stake_yes_side_succeeds() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 100_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
         let stake = s.client.stake(&user, &poll_id, &amount, &StakeSide::Yes);
 
         assert_eq!(stake.user, user);
         assert_eq!(stake.poll_id, poll_id);
         assert_eq!(stake.amount, amount);
-        assert_eq!(stake.side, StakeSide::Yes);
+        assert_eq!(stake.outcome, StakeSide::Yes as u32);
         assert!(!stake.claimed);
     }
 
     #[test]
     fn stake_no_side_succeeds() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 50_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
         let stake = s.client.stake(&user, &poll_id, &amount, &StakeSide::No);
 
-        assert_eq!(stake.side, StakeSide::No);
+        assert_eq!(stake.outcome, StakeSide::No as u32);
         assert_eq!(stake.amount, amount);
     }
 
@@ -321,7 +583,7 @@ mod test {
 
     #[test]
     fn stake_rejects_on_non_active_poll() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         // Manually set poll status to Locked
@@ -333,7 +595,7 @@ mod test {
         });
 
         let user = Address::generate(&s.env);
-        mint_tokens(&s, &user, 50_000_000);
+        s.mint(&user, 50_000_000);
 
         let err = s
             .client
@@ -344,7 +606,7 @@ mod test {
 
     #[test]
     fn stake_rejects_after_lock_time() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let lock_time = 1_500_000;
         let poll_id = create_test_poll(&s, lock_time);
 
@@ -352,7 +614,7 @@ mod test {
         s.env.ledger().with_mut(|l| l.timestamp = lock_time + 1);
 
         let user = Address::generate(&s.env);
-        mint_tokens(&s, &user, 50_000_000);
+        s.mint(&user, 50_000_000);
 
         let err = s
             .client
@@ -363,10 +625,10 @@ mod test {
 
     #[test]
     fn stake_rejects_double_stake() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
-        mint_tokens(&s, &user, 100_000_000);
+        s.mint(&user, 100_000_000);
 
         s.client.stake(&user, &poll_id, &50_000_000_i128, &StakeSide::Yes);
 
@@ -379,7 +641,7 @@ mod test {
 
     #[test]
     fn stake_rejects_zero_amount() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
 
@@ -392,7 +654,7 @@ mod test {
 
     #[test]
     fn stake_rejects_negative_amount() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
 
@@ -405,11 +667,11 @@ mod test {
 
     #[test]
     fn stake_rejects_below_minimum() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let small_amount: i128 = 1_000; // well below MIN_STAKE_AMOUNT
-        mint_tokens(&s, &user, small_amount);
+        s.mint(&user, small_amount);
 
         let err = s
             .client
@@ -422,7 +684,7 @@ mod test {
 
     #[test]
     fn pool_totals_update_correctly_with_multiple_stakers() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         let users: soroban_sdk::Vec<Address> = {
@@ -435,7 +697,7 @@ mod test {
         let amounts: [i128; 3] = [100_000_000, 200_000_000, 150_000_000];
 
         for i in 0..3u32 {
-            mint_tokens(&s, &users.get(i).unwrap(), amounts[i as usize]);
+            s.mint(&users.get(i).unwrap(), amounts[i as usize]);
         }
 
         // Two yes stakers, one no staker
@@ -444,32 +706,32 @@ mod test {
         s.client.stake(&users.get(2).unwrap(), &poll_id, &amounts[2], &StakeSide::Yes);
 
         let pool = s.client.get_pool_info(&poll_id);
-        assert_eq!(pool.yes_pool, amounts[0] + amounts[2]);
-        assert_eq!(pool.no_pool, amounts[1]);
-        assert_eq!(pool.yes_count, 2);
-        assert_eq!(pool.no_count, 1);
+        assert_eq!(pool.outcome_pools.get_unchecked(0), amounts[0] + amounts[2]);
+        assert_eq!(pool.outcome_pools.get_unchecked(1), amounts[1]);
+        assert_eq!(pool.outcome_counts.get_unchecked(0), 2);
+        assert_eq!(pool.outcome_counts.get_unchecked(1), 1);
     }
 
     #[test]
     fn token_transfer_occurs_correctly() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 100_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
-        assert_eq!(token_balance(&s, &user), amount);
-        assert_eq!(token_balance(&s, &s.contract_id), 0);
+        assert_eq!(s.balance(&user), amount);
+        assert_eq!(s.balance(&s.contract_id), 0);
 
         s.client.stake(&user, &poll_id, &amount, &StakeSide::Yes);
 
-        assert_eq!(token_balance(&s, &user), 0);
-        assert_eq!(token_balance(&s, &s.contract_id), amount);
+        assert_eq!(s.balance(&user), 0);
+        assert_eq!(s.balance(&s.contract_id), amount);
     }
 
     #[test]
     fn concurrent_staking_on_both_sides() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let base: i128 = 50_000_000;
 
@@ -480,7 +742,7 @@ mod test {
         for i in 1..=5_i128 {
             let u = Address::generate(&s.env);
             let amt = base * i;
-            mint_tokens(&s, &u, amt);
+            s.mint(&u, amt);
             s.client.stake(&u, &poll_id, &amt, &StakeSide::Yes);
             total_yes += amt;
         }
@@ -489,30 +751,388 @@ mod test {
         for i in 1..=3_i128 {
             let u = Address::generate(&s.env);
             let amt = base * (i + 1);
-            mint_tokens(&s, &u, amt);
+            s.mint(&u, amt);
             s.client.stake(&u, &poll_id, &amt, &StakeSide::No);
             total_no += amt;
         }
 
         let pool = s.client.get_pool_info(&poll_id);
-        assert_eq!(pool.yes_pool, total_yes);
-        assert_eq!(pool.no_pool, total_no);
-        assert_eq!(pool.yes_count, 5);
-        assert_eq!(pool.no_count, 3);
+        assert_eq!(pool.outcome_pools.get_unchecked(0), total_yes);
+        assert_eq!(pool.outcome_pools.get_unchecked(1), total_no);
+        assert_eq!(pool.outcome_counts.get_unchecked(0), 5);
+        assert_eq!(pool.outcome_counts.get_unchecked(1), 3);
 
         // Verify contract holds all tokens
-        assert_eq!(token_balance(&s, &s.contract_id), total_yes + total_no);
+        assert_eq!(s.balance(&s.contract_id), total_yes + total_no);
+    }
+
+    // ── Top-ups & hedging ─────────────────────────────────────────────────────
+
+    #[test]
+    fn top_up_grows_position_without_counting_new_staker() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 150_000_000);
+
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+        s.env.ledger().with_mut(|l| l.timestamp = 1_000_500);
+        let stake = s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::Yes);
+
+        assert_eq!(stake.amount, 150_000_000);
+        assert_eq!(stake.staked_at, 1_000_000);
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_pools.get_unchecked(0), 150_000_000);
+        assert_eq!(pool.outcome_counts.get_unchecked(0), 1);
+        assert_eq!(s.client.get_user_stakes(&user).len(), 1);
+
+        let history = s.client.get_stake_history(&poll_id, &user);
+        assert_eq!(
+            history,
+            soroban_sdk::vec![
+                &s.env,
+                StakeEntry { outcome: 0, amount: 100_000_000, at: 1_000_000 },
+                StakeEntry { outcome: 0, amount: 50_000_000, at: 1_000_500 },
+            ]
+        );
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 150_000_000);
+    }
+
+    #[test]
+    fn hedging_opens_a_second_position() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+        s.client.set_poll_hedging(&s.admin, &poll_id, &true);
+        assert!(s.client.is_hedging_enabled(&poll_id));
+
+        let user = Address::generate(&s.env);
+        s.mint(&user, 200_000_000);
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+        let hedge = s.client.stake(&user, &poll_id, &60_000_000, &StakeSide::No);
+        s.client.stake(&user, &poll_id, &40_000_000, &StakeSide::No);
+
+        assert_eq!(hedge.outcome, StakeSide::No as u32);
+        assert_eq!(s.client.get_stake_info(&poll_id, &user).amount, 100_000_000);
+        let positions = s.client.get_user_positions(&poll_id, &user);
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions.get_unchecked(1).amount, 100_000_000);
+
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_counts, soroban_sdk::vec![&s.env, 1, 1]);
+        assert_eq!(pool.outcome_pools, soroban_sdk::vec![&s.env, 100_000_000, 100_000_000]);
+    }
+
+    #[test]
+    fn set_poll_hedging_is_creator_only() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+
+        let err = s
+            .client
+            .try_set_poll_hedging(&Address::generate(&s.env), &poll_id, &true)
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::Unauthorized));
+        assert!(!s.client.is_hedging_enabled(&poll_id));
+    }
+
+    // ── Slippage protection ───────────────────────────────────────────────────
+
+    #[test]
+    fn stake_with_min_payout_honours_the_quote() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let no_user = Address::generate(&s.env);
+        s.mint(&no_user, 300_000_000);
+        s.client.stake(&no_user, &poll_id, &300_000_000, &StakeSide::No);
+
+        let user = Address::generate(&s.env);
+        s.mint(&user, 100_000_000);
+        let quote = s.client.calculate_potential_winnings(&poll_id, &StakeSide::Yes, &100_000_000);
+        let stake = s.client.stake_with_min_payout(&user, &poll_id, &100_000_000, &StakeSide::Yes, &quote);
+
+        assert_eq!(stake.amount, 100_000_000);
+    }
+
+    #[test]
+    fn stake_with_min_payout_rejects_when_odds_moved() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let no_user = Address::generate(&s.env);
+        s.mint(&no_user, 300_000_000);
+        s.client.stake(&no_user, &poll_id, &300_000_000, &StakeSide::No);
+
+        let user = Address::generate(&s.env);
+        s.mint(&user, 100_000_000);
+        let quote = s.client.calculate_potential_winnings(&poll_id, &StakeSide::Yes, &100_000_000);
+
+        // Another Yes stake lands between quote and inclusion
+        let rival = Address::generate(&s.env);
+        s.mint(&rival, 200_000_000);
+        s.client.stake(&rival, &poll_id, &200_000_000, &StakeSide::Yes);
+
+        let err = s
+            .client
+            .try_stake_with_min_payout(&user, &poll_id, &100_000_000, &StakeSide::Yes, &quote)
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::PayoutBelowMinimum));
+        assert_eq!(s.balance(&user), 100_000_000);
+    }
+
+    // ── Withdrawals ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn withdraw_stake_partially_shrinks_the_position() {
+        let s = TestMarket::with_oracle();
+        s.client.set_exit_fee_bps(&s.admin, &0);
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 100_000_000);
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+
+        s.env.ledger().with_mut(|l| l.timestamp = 1_000_500);
+        let paid = s.client.withdraw_stake(&user, &poll_id, &0, &40_000_000);
+
+        assert_eq!(paid, 40_000_000);
+        assert_eq!(s.balance(&user), 40_000_000);
+        assert_eq!(s.client.get_stake_info(&poll_id, &user).amount, 60_000_000);
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_pools.get_unchecked(0), 60_000_000);
+        assert_eq!(pool.outcome_counts.get_unchecked(0), 1);
+        assert_eq!(
+            s.client.get_stake_history(&poll_id, &user).last_unchecked(),
+            StakeEntry { outcome: 0, amount: -40_000_000, at: 1_000_500 }
+        );
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 60_000_000);
+    }
+
+    #[test]
+    fn withdraw_stake_in_full_forgets_the_staker() {
+        let s = TestMarket::with_oracle();
+        s.client.set_exit_fee_bps(&s.admin, &0);
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 100_000_000);
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::No);
+
+        s.client.withdraw_stake(&user, &poll_id, &1, &100_000_000);
+
+        assert!(!s.client.has_user_staked(&poll_id, &user));
+        assert_eq!(s.client.get_user_stakes(&user).len(), 0);
+        assert_eq!(s.client.get_user_positions(&poll_id, &user).len(), 0);
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_pools, soroban_sdk::vec![&s.env, 0, 0]);
+        assert_eq!(pool.outcome_counts, soroban_sdk::vec![&s.env, 0, 0]);
+        assert_eq!(s.client.get_platform_stats().total_value_locked, 0);
+        assert_eq!(s.balance(&user), 100_000_000);
+    }
+
+    #[test]
+    fn withdrawing_first_position_promotes_the_hedge() {
+        let s = TestMarket::with_oracle();
+        s.client.set_exit_fee_bps(&s.admin, &0);
+        let poll_id = create_test_poll(&s, 2_000_000);
+        s.client.set_poll_hedging(&s.admin, &poll_id, &true);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 150_000_000);
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+        s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::No);
+
+        s.client.withdraw_stake(&user, &poll_id, &0, &100_000_000);
+
+        let stake = s.client.get_stake_info(&poll_id, &user);
+        assert_eq!(stake.outcome, StakeSide::No as u32);
+        assert_eq!(stake.amount, 50_000_000);
+        assert_eq!(s.client.get_user_positions(&poll_id, &user).len(), 1);
+        assert!(s.client.has_user_staked(&poll_id, &user));
+        assert_eq!(s.client.get_pool_info(&poll_id).outcome_counts, soroban_sdk::vec![&s.env, 0, 1]);
+    }
+
+    #[test]
+    fn withdraw_stake_can_take_back_a_hedge() {
+        let s = TestMarket::with_oracle();
+        s.client.set_exit_fee_bps(&s.admin, &0);
+        let poll_id = create_test_poll(&s, 2_000_000);
+        s.client.set_poll_hedging(&s.admin, &poll_id, &true);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 150_000_000);
+        s.client.stake(&user, &poll_id, &100_000_000, &StakeSide::Yes);
+        s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::No);
+
+        s.client.withdraw_stake(&user, &poll_id, &1, &20_000_000);
+        let positions = s.client.get_user_positions(&poll_id, &user);
+        assert_eq!(positions.get_unchecked(1).amount, 30_000_000);
+
+        s.client.withdraw_stake(&user, &poll_id, &1, &30_000_000);
+        let stake = s.client.get_stake_info(&poll_id, &user);
+        assert_eq!(stake.outcome, StakeSide::Yes as u32);
+        assert_eq!(stake.amount, 100_000_000);
+        assert_eq!(s.client.get_user_positions(&poll_id, &user).len(), 1);
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_pools, soroban_sdk::vec![&s.env, 100_000_000, 0]);
+        assert_eq!(pool.outcome_counts, soroban_sdk::vec![&s.env, 1, 0]);
+        assert_eq!(s.balance(&user), 50_000_000);
+    }
+
+    #[test]
+    fn withdraw_stake_rejects_after_lock_time() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 1_500_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 50_000_000);
+        s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::Yes);
+
+        s.env.ledger().with_mut(|l| l.timestamp = 1_500_000);
+        let err = s
+            .client
+            .try_withdraw_stake(&user, &poll_id, &0, &50_000_000)
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::PollLocked));
+    }
+
+    #[test]
+    fn withdraw_stake_rejects_bad_amounts() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_test_poll(&s, 2_000_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 50_000_000);
+        s.client.stake(&user, &poll_id, &50_000_000, &StakeSide::Yes);
+
+        let over = s.client.try_withdraw_stake(&user, &poll_id, &0, &50_000_001).expect_err("over");
+        assert_eq!(over, Ok(PredictXError::InsufficientBalance));
+        let dust = s.client.try_withdraw_stake(&user, &poll_id, &0, &49_999_000).expect_err("dust");
+        assert_eq!(dust, Ok(PredictXError::StakeBelowMinimum));
+        let stranger = s
+            .client
+            .try_withdraw_stake(&Address::generate(&s.env), &poll_id, &0, &10_000_000)
+            .expect_err("stranger");
+        assert_eq!(stranger, Ok(PredictXError::NotStaker));
+        let unbacked = s.client.try_withdraw_stake(&user, &poll_id, &1, &10_000_000).expect_err("No");
+        assert_eq!(unbacked, Ok(PredictXError::NotStaker));
+    }
+
+    #[test]
+    fn withdraw_stake_sends_exit_fee_to_treasury() {
+        let m = TestMarket::with_oracle();
+        let match_id = m.create_match(2_003_600);
+        let poll_id = m.create_poll(&m.admin, match_id, PollCategory::PlayerEvent, 2_000_000);
+        let user = m.stake_as_new_user(poll_id, 500_000_000, StakeSide::Yes);
+
+        // Default exit fee is 2%
+        let paid = m.client.withdraw_stake(&user, &poll_id, &0, &500_000_000);
+
+        assert_eq!(paid, 490_000_000);
+        assert_eq!(m.balance(&user), 490_000_000);
+        assert_eq!(m.treasury.fees_collected(&FeeSource::ExitFee), 10_000_000);
+        assert_eq!(m.balance(&m.contract_id), 0);
+    }
+
+    #[test]
+    fn set_exit_fee_bps_rejects_more_than_everything() {
+        let s = TestMarket::with_oracle();
+        assert_eq!(s.client.get_exit_fee_bps(), predictx_shared::DEFAULT_EXIT_FEE_BPS);
+
+        let err = s
+            .client
+            .try_set_exit_fee_bps(&s.admin, &10_001)
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::InvalidFeeBps));
+    }
+
+    // ── Categorical polls ─────────────────────────────────────────────────────
+
+    /// Create a match + three-outcome poll locking at `lock_time`.  Returns poll_id.
+    fn create_categorical_poll(s: &TestMarket, lock_time: u64) -> u64 {
+        let match_id = s.client.create_match(
+            &s.admin,
+            &String::from_str(&s.env, "Arsenal"),
+            &String::from_str(&s.env, "Chelsea"),
+            &String::from_str(&s.env, "Premier League"),
+            &String::from_str(&s.env, "Emirates"),
+            &(lock_time + 3600),
+        );
+        s.client.create_categorical_poll(
+            &s.admin,
+            &match_id,
+            &String::from_str(&s.env, "Who scores first?"),
+            &PollCategory::PlayerEvent,
+            &None,
+            &soroban_sdk::vec![
+                &s.env,
+                String::from_str(&s.env, "Saka"),
+                String::from_str(&s.env, "Palmer"),
+                String::from_str(&s.env, "Nobody"),
+            ],
+            &lock_time,
+        )
+    }
+
+    #[test]
+    fn stake_outcome_updates_the_chosen_pool() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_categorical_poll(&s, 2_000_000);
+
+        for (outcome, amount) in [(2_u32, 30_000_000_i128), (0, 10_000_000), (2, 20_000_000)] {
+            let u = Address::generate(&s.env);
+            s.mint(&u, amount);
+            let stake = s.client.stake_outcome(&u, &poll_id, &outcome, &amount);
+            assert_eq!(stake.outcome, outcome);
+        }
+
+        let pool = s.client.get_pool_info(&poll_id);
+        assert_eq!(pool.outcome_pools, soroban_sdk::vec![&s.env, 10_000_000, 0, 50_000_000]);
+        assert_eq!(pool.outcome_counts, soroban_sdk::vec![&s.env, 1, 0, 2]);
+    }
+
+    #[test]
+    fn stake_outcome_rejects_out_of_range_index() {
+        let s = TestMarket::with_oracle();
+        let poll_id = create_categorical_poll(&s, 2_000_000);
+        let user = Address::generate(&s.env);
+        s.mint(&user, 50_000_000);
+
+        let err = s
+            .client
+            .try_stake_outcome(&user, &poll_id, &3, &50_000_000_i128)
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::InvalidOutcome));
+    }
+
+    #[test]
+    fn create_categorical_poll_rejects_single_outcome() {
+        let s = TestMarket::with_oracle();
+        let match_id = s.client.create_match(
+            &s.admin,
+            &String::from_str(&s.env, "Arsenal"),
+            &String::from_str(&s.env, "Chelsea"),
+            &String::from_str(&s.env, "Premier League"),
+            &String::from_str(&s.env, "Emirates"),
+            &2_003_600,
+        );
+
+        let err = s
+            .client
+            .try_create_categorical_poll(
+                &s.admin,
+                &match_id,
+                &String::from_str(&s.env, "Who scores first?"),
+                &PollCategory::PlayerEvent,
+                &None,
+                &soroban_sdk::vec![&s.env, String::from_str(&s.env, "Saka")],
+                &2_000_000,
+            )
+            .expect_err("should reject");
+        assert_eq!(err, Ok(PredictXError::InvalidOutcome));
     }
 
     // ── View function tests ───────────────────────────────────────────────────
 
     #[test]
     fn get_stake_returns_correct_record() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 75_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
         s.client.stake(&user, &poll_id, &amount, &StakeSide::Yes);
 
@@ -520,19 +1140,19 @@ mod test {
         assert_eq!(stake.user, user);
         assert_eq!(stake.poll_id, poll_id);
         assert_eq!(stake.amount, amount);
-        assert_eq!(stake.side, StakeSide::Yes);
+        assert_eq!(stake.outcome, StakeSide::Yes as u32);
         assert!(!stake.claimed);
     }
 
     #[test]
     fn get_user_stakes_tracks_poll_ids() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id1 = create_test_poll(&s, 2_000_000);
         let poll_id2 = create_test_poll(&s, 2_000_000);
 
         let user = Address::generate(&s.env);
         let amount: i128 = 50_000_000;
-        mint_tokens(&s, &user, amount * 2);
+        s.mint(&user, amount * 2);
 
         s.client.stake(&user, &poll_id1, &amount, &StakeSide::Yes);
         s.client.stake(&user, &poll_id2, &amount, &StakeSide::No);
@@ -545,11 +1165,11 @@ mod test {
 
     #[test]
     fn has_staked_returns_correct_value() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 50_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
         assert!(!s.client.has_user_staked(&poll_id, &user));
         s.client.stake(&user, &poll_id, &amount, &StakeSide::Yes);
@@ -558,15 +1178,15 @@ mod test {
 
     #[test]
     fn platform_stats_update_on_stake() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         let user1 = Address::generate(&s.env);
         let user2 = Address::generate(&s.env);
         let amount1: i128 = 100_000_000;
         let amount2: i128 = 200_000_000;
-        mint_tokens(&s, &user1, amount1);
-        mint_tokens(&s, &user2, amount2);
+        s.mint(&user1, amount1);
+        s.mint(&user2, amount2);
 
         s.client.stake(&user1, &poll_id, &amount1, &StakeSide::Yes);
         s.client.stake(&user2, &poll_id, &amount2, &StakeSide::No);
@@ -580,7 +1200,7 @@ mod test {
 
     #[test]
     fn potential_winnings_calculation_accuracy() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         // Build a pool: Yes = 7_000 tokens, No = 3_000 tokens
@@ -590,8 +1210,8 @@ mod test {
 
         let yes_user = Address::generate(&s.env);
         let no_user = Address::generate(&s.env);
-        mint_tokens(&s, &yes_user, yes_amount);
-        mint_tokens(&s, &no_user, no_amount);
+        s.mint(&yes_user, yes_amount);
+        s.mint(&no_user, no_amount);
 
         s.client.stake(&yes_user, &poll_id, &yes_amount, &StakeSide::Yes);
         s.client.stake(&no_user, &poll_id, &no_amount, &StakeSide::No);
@@ -618,7 +1238,7 @@ mod test {
 
     #[test]
     fn potential_winnings_first_staker_on_empty_pool() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         let amount: i128 = 100_000_000;
@@ -636,7 +1256,7 @@ mod test {
 
     #[test]
     fn potential_winnings_rejects_zero_amount() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
 
         let err = s
@@ -650,17 +1270,17 @@ mod test {
 
     #[test]
     fn get_contract_balance_returns_zero_initially() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         assert_eq!(s.client.get_contract_balance(), 0);
     }
 
     #[test]
     fn get_contract_balance_reflects_staked_tokens() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let amount: i128 = 100_000_000;
-        mint_tokens(&s, &user, amount);
+        s.mint(&user, amount);
 
         s.client.stake(&user, &poll_id, &amount, &StakeSide::Yes);
 
@@ -669,13 +1289,13 @@ mod test {
 
     #[test]
     fn get_token_address_returns_stored_address() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         assert_eq!(s.client.get_token_address(), s.token_addr);
     }
 
     #[test]
     fn get_treasury_address_returns_stored_address() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let treasury: Address = s.env.as_contract(&s.contract_id, || {
             s.env.storage().instance().get(&DataKey::TreasuryAddress).unwrap()
         });
@@ -684,30 +1304,30 @@ mod test {
 
     #[test]
     fn get_platform_fee_bps_returns_configured_value() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         assert_eq!(s.client.get_platform_fee_bps(), 500);
     }
 
     #[test]
     fn transfer_to_contract_moves_exact_amount() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let user = Address::generate(&s.env);
         let deposit: i128 = 200_000_000;
-        mint_tokens(&s, &user, deposit);
+        s.mint(&user, deposit);
 
         let stake_amount: i128 = 150_000_000;
         s.client.stake(&user, &poll_id, &stake_amount, &StakeSide::Yes);
 
         // User should have deposit - stake_amount left
-        assert_eq!(token_balance(&s, &user), deposit - stake_amount);
+        assert_eq!(s.balance(&user), deposit - stake_amount);
         // Contract should hold exactly stake_amount
-        assert_eq!(token_balance(&s, &s.contract_id), stake_amount);
+        assert_eq!(s.balance(&s.contract_id), stake_amount);
     }
 
     #[test]
     fn multiple_stakes_accumulate_contract_balance() {
-        let s = setup();
+        let s = TestMarket::with_oracle();
         let poll_id = create_test_poll(&s, 2_000_000);
         let base: i128 = 50_000_000;
         let mut total: i128 = 0;
@@ -715,13 +1335,13 @@ mod test {
         for i in 1..=4_i128 {
             let u = Address::generate(&s.env);
             let amt = base * i;
-            mint_tokens(&s, &u, amt);
+            s.mint(&u, amt);
             let side = if i % 2 == 0 { StakeSide::No } else { StakeSide::Yes };
             s.client.stake(&u, &poll_id, &amt, &side);
             total += amt;
         }
 
         assert_eq!(s.client.get_contract_balance(), total);
-        assert_eq!(token_balance(&s, &s.contract_id), total);
+        assert_eq!(s.balance(&s.contract_id), total);
     }
 }
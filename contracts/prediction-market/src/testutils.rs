@@ -0,0 +1,112 @@
+//! Fixture shared by the module test suites: a market wired to a real token
+//! and a `MockTreasury`, plus the calls most tests start from.
+
+use predictx_shared::testutils::{MockTreasury, MockTreasuryClient};
+use predictx_shared::{PollCategory, StakeSide};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{voting_oracle, PredictionMarket, PredictionMarketClient};
+
+pub(crate) struct TestMarket<'a> {
+    pub env: Env,
+    pub admin: Address,
+    pub oracle_id: Address,
+    pub token_addr: Address,
+    pub treasury: MockTreasuryClient<'a>,
+    pub contract_id: Address,
+    pub client: PredictionMarketClient<'a>,
+}
+
+impl TestMarket<'static> {
+    /// Market with a placeholder oracle address; oracle-only entrypoints are
+    /// called directly under `mock_all_auths`.
+    pub fn new() -> Self {
+        let env = Env::default();
+        let oracle_id = Address::generate(&env);
+        Self::build(env, oracle_id)
+    }
+
+    /// Market wired to a deployed voting oracle.
+    pub fn with_oracle() -> Self {
+        let env = Env::default();
+        let oracle_id = env.register(voting_oracle::WASM, ());
+        let market = Self::build(env, oracle_id);
+        let oracle = market.oracle();
+        oracle.initialize(&market.admin);
+        oracle.set_prediction_market(&market.contract_id);
+        market
+    }
+
+    fn build(env: Env, oracle_id: Address) -> Self {
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_addr = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let treasury = MockTreasury::register(&env, &token_addr);
+
+        let contract_id = env.register(PredictionMarket, ());
+        let client = PredictionMarketClient::new(&env, &contract_id);
+        client.initialize(&admin, &oracle_id, &token_addr, &treasury.address, &500_u32);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000_000);
+
+        TestMarket { env, admin, oracle_id, token_addr, treasury, contract_id, client }
+    }
+}
+
+impl<'a> TestMarket<'a> {
+    pub fn oracle(&self) -> voting_oracle::Client<'a> {
+        voting_oracle::Client::new(&self.env, &self.oracle_id)
+    }
+
+    pub fn set_time(&self, timestamp: u64) {
+        self.env.ledger().with_mut(|l| l.timestamp = timestamp);
+    }
+
+    pub fn mint(&self, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &self.token_addr).mint(to, &amount);
+    }
+
+    pub fn balance(&self, who: &Address) -> i128 {
+        token::Client::new(&self.env, &self.token_addr).balance(who)
+    }
+
+    /// Arsenal v Chelsea kicking off at `kickoff`.
+    pub fn create_match(&self, kickoff: u64) -> u64 {
+        self.client.create_match(
+            &self.admin,
+            &String::from_str(&self.env, "Arsenal"),
+            &String::from_str(&self.env, "Chelsea"),
+            &String::from_str(&self.env, "Premier League"),
+            &String::from_str(&self.env, "Emirates"),
+            &kickoff,
+        )
+    }
+
+    pub fn create_poll(
+        &self,
+        creator: &Address,
+        match_id: u64,
+        category: PollCategory,
+        lock_time: u64,
+    ) -> u64 {
+        self.client.create_poll(
+            creator,
+            &match_id,
+            &String::from_str(&self.env, "Will Palmer score?"),
+            &category,
+            &None,
+            &lock_time,
+        )
+    }
+
+    pub fn stake_as_new_user(&self, poll_id: u64, amount: i128, side: StakeSide) -> Address {
+        let user = Address::generate(&self.env);
+        self.mint(&user, amount);
+        self.client.stake(&user, &poll_id, &amount, &side);
+        user
+    }
+}
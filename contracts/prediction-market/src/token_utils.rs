@@ -1,5 +1,5 @@
 use soroban_sdk::{token, Address, Env};
-use predictx_shared::PredictXError;
+use predictx_shared::{pay_treasury, FeeSource, PredictXError};
 use crate::DataKey;
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -28,6 +28,29 @@ pub fn get_platform_fee_bps(env: &Env) -> u32 {
         .unwrap_or(predictx_shared::PLATFORM_FEE_BPS)
 }
 
+/// Retrieve the poll creator's share of winning payouts in basis points;
+/// `0` until the admin sets one.
+pub fn get_creator_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorFeeBps)
+        .unwrap_or(0)
+}
+
+/// Everything deducted from a winning payout, in basis points: the platform
+/// fee plus the creator fee.
+pub fn get_payout_fee_bps(env: &Env) -> u32 {
+    get_platform_fee_bps(env) + get_creator_fee_bps(env)
+}
+
+/// Retrieve the fee for withdrawing a stake before lock, in basis points.
+pub fn get_exit_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExitFeeBps)
+        .unwrap_or(predictx_shared::DEFAULT_EXIT_FEE_BPS)
+}
+
 // ── Token operations ──────────────────────────────────────────────────────────
 
 /// Transfer tokens **from** a user **to** this contract.
@@ -50,10 +73,13 @@ pub fn transfer_from_contract(env: &Env, to: &Address, amount: i128) -> Result<(
     Ok(())
 }
 
-/// Transfer tokens **from** this contract **to** the treasury address.
-pub fn transfer_to_treasury(env: &Env, amount: i128) -> Result<(), PredictXError> {
+/// Deposit tokens **from** this contract into the `Treasury` contract,
+/// booked under `source` in its fee accounting.
+pub fn transfer_to_treasury(env: &Env, amount: i128, source: FeeSource) -> Result<(), PredictXError> {
+    let token_addr = get_token_address(env)?;
     let treasury = get_treasury_address(env)?;
-    transfer_from_contract(env, &treasury, amount)
+    pay_treasury(env, &token_addr, &treasury, amount, source);
+    Ok(())
 }
 
 /// Get the contract's token balance.
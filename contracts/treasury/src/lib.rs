@@ -1,7 +1,7 @@
 #![no_std]
 
-use predictx_shared::PredictXError;
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+use predictx_shared::{FeeSource, PredictXError};
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Symbol};
 
 #[contract]
 pub struct Treasury;
@@ -10,7 +10,13 @@ pub struct Treasury;
 #[derive(Clone)]
 enum DataKey {
     Admin,
-    Balance(Address),
+    TokenAddress,
+    /// Tokens currently held, as booked by deposits and withdrawals.
+    TreasuryBalance,
+    /// Lifetime deposits per `FeeSource`.
+    FeesCollected(FeeSource),
+    TotalFeesCollected,
+    TotalWithdrawn,
 }
 
 fn get_admin(env: &Env) -> Result<Address, PredictXError> {
@@ -20,22 +26,33 @@ fn get_admin(env: &Env) -> Result<Address, PredictXError> {
         .ok_or(PredictXError::NotInitialized)
 }
 
-fn get_balance(env: &Env, who: &Address) -> i128 {
+fn get_token(env: &Env) -> Result<Address, PredictXError> {
     env.storage()
-        .persistent()
-        .get(&DataKey::Balance(who.clone()))
-        .unwrap_or(0_i128)
+        .instance()
+        .get(&DataKey::TokenAddress)
+        .ok_or(PredictXError::NotInitialized)
+}
+
+fn get_amount(env: &Env, key: &DataKey) -> i128 {
+    env.storage().instance().get(key).unwrap_or(0_i128)
+}
+
+fn add_amount(env: &Env, key: &DataKey, delta: i128) -> i128 {
+    let value = get_amount(env, key) + delta;
+    env.storage().instance().set(key, &value);
+    value
 }
 
 #[contractimpl]
 impl Treasury {
-    pub fn initialize(env: Env, admin: Address) -> Result<(), PredictXError> {
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), PredictXError> {
         if env.storage().instance().has(&DataKey::Admin) {
             return Err(PredictXError::AlreadyInitialized);
         }
 
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::TokenAddress, &token);
         Ok(())
     }
 
@@ -43,30 +60,80 @@ impl Treasury {
         get_admin(&env)
     }
 
-    /// Placeholder accounting method.
+    pub fn token(env: Env) -> Result<Address, PredictXError> {
+        get_token(&env)
+    }
+
+    /// Pull `amount` of the platform token from `from` and book it under `source`.
     ///
-    /// Real token transfers are integrated in later issues.
-    pub fn deposit(env: Env, from: Address, amount: i128) -> Result<i128, PredictXError> {
+    /// Returns the treasury balance after the deposit.
+    pub fn deposit_fees(
+        env: Env,
+        from: Address,
+        amount: i128,
+        source: FeeSource,
+    ) -> Result<i128, PredictXError> {
         if amount <= 0 {
             return Err(PredictXError::StakeAmountZero);
         }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(PredictXError::NotInitialized);
-        }
+        let token = get_token(&env)?;
         from.require_auth();
 
-        let new_balance = get_balance(&env, &from) + amount;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Balance(from), &new_balance);
-        Ok(new_balance)
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+
+        add_amount(&env, &DataKey::FeesCollected(source), amount);
+        add_amount(&env, &DataKey::TotalFeesCollected, amount);
+        let balance = add_amount(&env, &DataKey::TreasuryBalance, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "FeesDeposited"), source, from),
+            amount,
+        );
+        Ok(balance)
     }
 
-    pub fn balance(env: Env, who: Address) -> Result<i128, PredictXError> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(PredictXError::NotInitialized);
+    /// Send `amount` of collected fees to `to`. Admin only.
+    ///
+    /// Returns the treasury balance after the withdrawal.
+    pub fn withdraw_fees(env: Env, to: Address, amount: i128) -> Result<i128, PredictXError> {
+        get_admin(&env)?.require_auth();
+        if amount <= 0 {
+            return Err(PredictXError::StakeAmountZero);
         }
-        Ok(get_balance(&env, &who))
+        if amount > get_amount(&env, &DataKey::TreasuryBalance) {
+            return Err(PredictXError::InsufficientBalance);
+        }
+
+        let balance = add_amount(&env, &DataKey::TreasuryBalance, -amount);
+        add_amount(&env, &DataKey::TotalWithdrawn, amount);
+        token::Client::new(&env, &get_token(&env)?).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        env.events().publish((Symbol::new(&env, "FeesWithdrawn"), to), amount);
+        Ok(balance)
+    }
+
+    /// Tokens currently held by the treasury.
+    pub fn balance(env: Env) -> i128 {
+        get_amount(&env, &DataKey::TreasuryBalance)
+    }
+
+    /// Lifetime deposits across every source.
+    pub fn total_fees_collected(env: Env) -> i128 {
+        get_amount(&env, &DataKey::TotalFeesCollected)
+    }
+
+    /// Lifetime deposits booked under `source`.
+    pub fn fees_collected(env: Env, source: FeeSource) -> i128 {
+        get_amount(&env, &DataKey::FeesCollected(source))
+    }
+
+    /// Lifetime withdrawals by the admin.
+    pub fn total_withdrawn(env: Env) -> i128 {
+        get_amount(&env, &DataKey::TotalWithdrawn)
     }
 }
 
@@ -78,20 +145,91 @@ mod test {
     use super::*;
     use soroban_sdk::testutils::Address as _;
 
-    #[test]
-    fn deposit_tracks_balance() {
+    struct TestSetup<'a> {
+        env: Env,
+        admin: Address,
+        contract_id: Address,
+        client: TreasuryClient<'a>,
+        token: token::Client<'a>,
+    }
+
+    fn setup() -> TestSetup<'static> {
         let env = Env::default();
         env.mock_all_auths();
 
+        let token_admin = Address::generate(&env);
+        let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+        let token = token::Client::new(&env, &token_id);
+
         let contract_id = env.register(Treasury, ());
         let client = TreasuryClient::new(&env, &contract_id);
-
         let admin = Address::generate(&env);
-        client.initialize(&admin);
+        client.initialize(&admin, &token_id);
+
+        TestSetup { env, admin, contract_id, client, token }
+    }
+
+    fn funded_user(s: &TestSetup, amount: i128) -> Address {
+        let user = Address::generate(&s.env);
+        token::StellarAssetClient::new(&s.env, &s.token.address).mint(&user, &amount);
+        user
+    }
+
+    #[test]
+    fn deposit_fees_moves_tokens_and_books_source() {
+        let s = setup();
+        let market = funded_user(&s, 100);
+
+        assert_eq!(s.client.deposit_fees(&market, &60, &FeeSource::PlatformFee), 60);
+        assert_eq!(s.client.deposit_fees(&market, &40, &FeeSource::DisputeFee), 100);
+
+        assert_eq!(s.token.balance(&s.contract_id), 100);
+        assert_eq!(s.token.balance(&market), 0);
+        assert_eq!(s.client.fees_collected(&FeeSource::PlatformFee), 60);
+        assert_eq!(s.client.fees_collected(&FeeSource::DisputeFee), 40);
+        assert_eq!(s.client.fees_collected(&FeeSource::SlashedBond), 0);
+        assert_eq!(s.client.total_fees_collected(), 100);
+    }
 
-        let user = Address::generate(&env);
-        assert_eq!(client.deposit(&user, &10_i128), 10_i128);
-        assert_eq!(client.deposit(&user, &5_i128), 15_i128);
-        assert_eq!(client.balance(&user), 15_i128);
+    #[test]
+    fn deposit_fees_rejects_zero_amount() {
+        let s = setup();
+        let user = funded_user(&s, 10);
+        let err = s
+            .client
+            .try_deposit_fees(&user, &0, &FeeSource::PlatformFee)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::StakeAmountZero);
+    }
+
+    #[test]
+    fn withdraw_fees_pays_out_and_reconciles() {
+        let s = setup();
+        let market = funded_user(&s, 100);
+        s.client.deposit_fees(&market, &100, &FeeSource::SlashedBond);
+
+        let recipient = Address::generate(&s.env);
+        assert_eq!(s.client.withdraw_fees(&recipient, &30), 70);
+        assert_eq!(s.env.auths()[0].0, s.admin);
+
+        assert_eq!(s.token.balance(&recipient), 30);
+        assert_eq!(s.client.balance(), s.token.balance(&s.contract_id));
+        assert_eq!(s.client.total_withdrawn(), 30);
+        assert_eq!(s.client.total_fees_collected(), 100);
+    }
+
+    #[test]
+    fn withdraw_fees_rejects_overdraw() {
+        let s = setup();
+        let market = funded_user(&s, 10);
+        s.client.deposit_fees(&market, &10, &FeeSource::PlatformFee);
+
+        let err = s
+            .client
+            .try_withdraw_fees(&Address::generate(&s.env), &11)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, PredictXError::InsufficientBalance);
     }
 }
@@ -0,0 +1,411 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+use predictx_shared::{
+    PollStatus, OracleError, VoteTally, ADMIN_REVIEW_THRESHOLD_BPS, AUTO_RESOLVE_THRESHOLD_BPS,
+    BPS_DENOMINATOR,
+};
+use crate::{DataKey, get_market, market::PredictionMarketClient, require_admin, rewards, voting};
+
+/// Outcome of applying the consensus thresholds to a poll's vote tally.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Consensus {
+    /// Voting window still open.
+    Pending,
+    /// ≥ `AUTO_RESOLVE_THRESHOLD_BPS` agree on Yes (`true`) or No (`false`).
+    AutoResolve(bool),
+    /// ≥ `AUTO_RESOLVE_THRESHOLD_BPS` voted "Unclear" — the poll is voided and
+    /// every stake refunded.
+    Void,
+    /// Leading choice between `ADMIN_REVIEW_THRESHOLD_BPS` and the auto
+    /// threshold — an admin must verify.
+    AdminReview,
+    /// Leading choice below `ADMIN_REVIEW_THRESHOLD_BPS`, or nobody voted.
+    NotReached,
+}
+
+/// Audit record written by `admin_verify`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminVerification {
+    pub admin: Address,
+    pub outcome: bool,
+    /// IPFS hash or external reference to the admin's reasoning.
+    pub reasoning_hash: String,
+    pub verified_at: u64,
+}
+
+// ── Threshold maths ───────────────────────────────────────────────────────────
+
+/// Share of all voters backing the leading choice, in BPS, plus that choice
+/// (`Some(true)` = Yes, `Some(false)` = No, `None` = Unclear).
+///
+/// Ties between Yes and No are reported as Unclear.
+fn leading_share(tally: &VoteTally) -> (Option<bool>, u32) {
+    if tally.total_voters == 0 {
+        return (None, 0);
+    }
+    let (choice, votes) = if tally.yes_votes > tally.no_votes && tally.yes_votes > tally.unclear_votes {
+        (Some(true), tally.yes_votes)
+    } else if tally.no_votes > tally.yes_votes && tally.no_votes > tally.unclear_votes {
+        (Some(false), tally.no_votes)
+    } else {
+        (None, tally.yes_votes.max(tally.no_votes).max(tally.unclear_votes))
+    };
+    let share = (votes as u64 * BPS_DENOMINATOR as u64 / tally.total_voters as u64) as u32;
+    (choice, share)
+}
+
+fn evaluate(env: &Env, tally: &VoteTally) -> Consensus {
+    if env.ledger().timestamp() < tally.voting_end_time {
+        return Consensus::Pending;
+    }
+    let (choice, share) = leading_share(tally);
+    match choice {
+        Some(outcome) if share >= AUTO_RESOLVE_THRESHOLD_BPS => Consensus::AutoResolve(outcome),
+        None if share >= AUTO_RESOLVE_THRESHOLD_BPS => Consensus::Void,
+        _ if share >= ADMIN_REVIEW_THRESHOLD_BPS => Consensus::AdminReview,
+        _ => Consensus::NotReached,
+    }
+}
+
+// ── Resolution ────────────────────────────────────────────────────────────────
+
+/// Read-only view of where a poll's vote currently stands.
+pub fn get_consensus(env: &Env, poll_id: u64) -> Result<Consensus, OracleError> {
+    let tally = voting::load_tally(env, poll_id)?;
+    Ok(evaluate(env, &tally))
+}
+
+/// Close a poll's vote and push the result into `PredictionMarket`.
+///
+/// Permissionless once the voting window has ended:
+/// - above `AUTO_RESOLVE_THRESHOLD_BPS` the poll is resolved immediately and
+///   the voter reward pool is funded, or voided if the majority voted
+///   "Unclear";
+/// - between the two thresholds the poll is moved to `AdminReview`;
+/// - below `ADMIN_REVIEW_THRESHOLD_BPS` this returns `ConsensusNotReached`.
+pub fn finalize_voting(env: &Env, poll_id: u64) -> Result<Consensus, OracleError> {
+    let tally = voting::load_tally(env, poll_id)?;
+    let consensus = evaluate(env, &tally);
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+
+    match consensus {
+        Consensus::Pending => return Err(OracleError::VotingStillOpen),
+        Consensus::NotReached => return Err(OracleError::ConsensusNotReached),
+        Consensus::AutoResolve(outcome) => {
+            market.resolve_poll(&poll_id, &outcome);
+            rewards::fund_if_earned(env, &market, poll_id, outcome);
+            let (_, share) = leading_share(&tally);
+            env.events().publish(
+                (Symbol::new(env, "AutoResolved"), poll_id),
+                (outcome, share),
+            );
+        }
+        Consensus::Void => {
+            market.void_poll(&poll_id, &true);
+            env.events().publish(
+                (Symbol::new(env, "AutoVoided"), poll_id),
+                leading_share(&tally).1,
+            );
+        }
+        Consensus::AdminReview => {
+            market.escalate_poll(&poll_id);
+            env.events().publish(
+                (Symbol::new(env, "AdminReviewRequired"), poll_id),
+                leading_share(&tally).1,
+            );
+        }
+    }
+
+    Ok(consensus)
+}
+
+/// Admin decision for polls the community could not settle on its own.
+///
+/// Allowed once voting has closed without auto-resolution consensus; admins
+/// can never override a vote that cleared `AUTO_RESOLVE_THRESHOLD_BPS`.
+pub fn admin_verify(
+    env: &Env,
+    admin: Address,
+    poll_id: u64,
+    outcome: bool,
+    reasoning_hash: String,
+) -> Result<(), OracleError> {
+    require_admin(env, &admin)?;
+
+    let tally = voting::load_tally(env, poll_id)?;
+    ensure_admin_may_decide(env, &tally)?;
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    market.resolve_poll(&poll_id, &outcome);
+    rewards::fund_if_earned(env, &market, poll_id, outcome);
+
+    let record = AdminVerification {
+        admin: admin.clone(),
+        outcome,
+        reasoning_hash,
+        verified_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminVerification(poll_id), &record);
+
+    env.events().publish(
+        (Symbol::new(env, "AdminVerified"), poll_id, admin),
+        outcome,
+    );
+
+    Ok(())
+}
+
+/// Admin decision that a poll has no winner (e.g. the player did not start),
+/// making every stake refundable in full.
+///
+/// Same rules as `admin_verify` for polls that went to a vote; categorical
+/// polls may be voided once they are in `AdminReview`.
+pub fn admin_void(
+    env: &Env,
+    admin: Address,
+    poll_id: u64,
+    reasoning_hash: String,
+) -> Result<(), OracleError> {
+    require_admin(env, &admin)?;
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    let poll = market.get_poll(&poll_id);
+    if poll.outcomes.len() > 2 {
+        if poll.status != PollStatus::AdminReview {
+            return Err(OracleError::ConsensusNotReached);
+        }
+    } else {
+        ensure_admin_may_decide(env, &voting::load_tally(env, poll_id)?)?;
+    }
+    market.void_poll(&poll_id, &false);
+
+    env.events().publish(
+        (Symbol::new(env, "AdminVoided"), poll_id, admin),
+        reasoning_hash,
+    );
+
+    Ok(())
+}
+
+/// Admins may only step in once voting has closed without a decisive result;
+/// they can never override a vote that cleared `AUTO_RESOLVE_THRESHOLD_BPS`.
+fn ensure_admin_may_decide(env: &Env, tally: &VoteTally) -> Result<(), OracleError> {
+    match evaluate(env, tally) {
+        Consensus::Pending => Err(OracleError::VotingStillOpen),
+        Consensus::AutoResolve(_) | Consensus::Void => Err(OracleError::Unauthorized),
+        Consensus::AdminReview | Consensus::NotReached => Ok(()),
+    }
+}
+
+/// Settle a categorical poll by naming its winning outcome. Community votes are
+/// Yes/No only, so multi-outcome polls are always decided by an admin once
+/// the market has sent them to review.
+pub fn admin_resolve_outcome(
+    env: &Env,
+    admin: Address,
+    poll_id: u64,
+    outcome_index: u32,
+    reasoning_hash: String,
+) -> Result<(), OracleError> {
+    require_admin(env, &admin)?;
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    let poll = market.get_poll(&poll_id);
+    if poll.outcomes.len() <= 2 {
+        return Err(OracleError::InvalidOutcome);
+    }
+    if poll.status != PollStatus::AdminReview {
+        return Err(OracleError::ConsensusNotReached);
+    }
+    market.resolve_poll_outcome(&poll_id, &outcome_index);
+
+    env.events().publish(
+        (Symbol::new(env, "AdminResolvedOutcome"), poll_id, admin),
+        (outcome_index, reasoning_hash),
+    );
+
+    Ok(())
+}
+
+pub fn get_admin_verification(env: &Env, poll_id: u64) -> Option<AdminVerification> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdminVerification(poll_id))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+    use predictx_shared::{OracleError, VoteChoice, VOTING_WINDOW_SECS};
+    use super::Consensus;
+    use crate::testutils::{MockMarket, MockMarketClient};
+    use crate::{VotingOracle, VotingOracleClient};
+
+    const START: u64 = 1_000_000;
+    const POLL: u64 = 1;
+
+    fn setup() -> (Env, Address, VotingOracleClient<'static>, MockMarketClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = START);
+
+        let market = MockMarket::register(&env);
+
+        let admin = Address::generate(&env);
+        let oracle_id = env.register(VotingOracle, ());
+        let oracle = VotingOracleClient::new(&env, &oracle_id);
+        oracle.initialize(&admin);
+        oracle.set_prediction_market(&market.address);
+        oracle.start_voting(&POLL);
+
+        (env, admin, oracle, market)
+    }
+
+    fn vote(env: &Env, oracle: &VotingOracleClient, yes: u32, no: u32, unclear: u32) {
+        for (count, choice) in [(yes, VoteChoice::Yes), (no, VoteChoice::No), (unclear, VoteChoice::Unclear)] {
+            for _ in 0..count {
+                oracle.cast_vote(&Address::generate(env), &POLL, &choice);
+            }
+        }
+        env.ledger().with_mut(|l| l.timestamp = START + VOTING_WINDOW_SECS);
+    }
+
+    #[test]
+    fn consensus_pending_while_window_open() {
+        let (_, _, oracle, _) = setup();
+        assert_eq!(oracle.get_consensus(&POLL), Consensus::Pending);
+        let err = oracle.try_finalize_voting(&POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::VotingStillOpen);
+    }
+
+    #[test]
+    fn finalize_auto_resolves_above_85_percent() {
+        let (env, _, oracle, market) = setup();
+        vote(&env, &oracle, 1, 17, 2); // 85% No
+
+        assert_eq!(oracle.finalize_voting(&POLL), Consensus::AutoResolve(false));
+        assert_eq!(market.resolved(&POLL), Some(false));
+    }
+
+    #[test]
+    fn finalize_escalates_between_60_and_85_percent() {
+        let (env, _, oracle, market) = setup();
+        vote(&env, &oracle, 7, 3, 0); // 70% Yes
+
+        assert_eq!(oracle.finalize_voting(&POLL), Consensus::AdminReview);
+        assert!(market.escalated(&POLL));
+        assert_eq!(market.resolved(&POLL), None);
+    }
+
+    #[test]
+    fn finalize_voids_overwhelming_unclear_vote() {
+        let (env, _, oracle, market) = setup();
+        vote(&env, &oracle, 0, 1, 9);
+
+        assert_eq!(oracle.finalize_voting(&POLL), Consensus::Void);
+        assert!(market.voided(&POLL));
+        assert!(market.slashed(&POLL));
+        assert_eq!(market.resolved(&POLL), None);
+    }
+
+    #[test]
+    fn finalize_escalates_unclear_majority() {
+        let (env, _, oracle, market) = setup();
+        vote(&env, &oracle, 2, 1, 7);
+
+        assert_eq!(oracle.finalize_voting(&POLL), Consensus::AdminReview);
+        assert!(market.escalated(&POLL));
+        assert!(!market.voided(&POLL));
+    }
+
+    #[test]
+    fn finalize_rejects_below_60_percent() {
+        let (env, _, oracle, market) = setup();
+        vote(&env, &oracle, 11, 9, 0); // 55% Yes
+
+        let err = oracle.try_finalize_voting(&POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::ConsensusNotReached);
+        assert!(!market.escalated(&POLL));
+    }
+
+    #[test]
+    fn finalize_rejects_when_nobody_voted() {
+        let (env, _, oracle, _) = setup();
+        vote(&env, &oracle, 0, 0, 0);
+
+        let err = oracle.try_finalize_voting(&POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::ConsensusNotReached);
+    }
+
+    #[test]
+    fn admin_verify_resolves_contested_poll() {
+        let (env, admin, oracle, market) = setup();
+        vote(&env, &oracle, 7, 3, 0);
+        let reasoning = String::from_str(&env, "ipfs://QmReasoning");
+
+        oracle.admin_verify(&admin, &POLL, &false, &reasoning);
+
+        assert_eq!(market.resolved(&POLL), Some(false));
+        let record = oracle.get_admin_verification(&POLL).unwrap();
+        assert_eq!(record.admin, admin);
+        assert!(!record.outcome);
+        assert_eq!(record.reasoning_hash, reasoning);
+    }
+
+    #[test]
+    fn admin_verify_cannot_override_auto_consensus() {
+        let (env, admin, oracle, _) = setup();
+        vote(&env, &oracle, 9, 1, 0);
+
+        let err = oracle
+            .try_admin_verify(&admin, &POLL, &false, &String::from_str(&env, "x"))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::Unauthorized);
+    }
+
+    #[test]
+    fn admin_void_settles_contested_poll() {
+        let (env, admin, oracle, market) = setup();
+        vote(&env, &oracle, 3, 2, 5);
+
+        oracle.admin_void(&admin, &POLL, &String::from_str(&env, "ipfs://QmDidNotStart"));
+        assert!(market.voided(&POLL));
+        assert!(!market.slashed(&POLL));
+    }
+
+    #[test]
+    fn admin_void_cannot_override_auto_consensus() {
+        let (env, admin, oracle, market) = setup();
+        vote(&env, &oracle, 9, 1, 0);
+
+        let err = oracle
+            .try_admin_void(&admin, &POLL, &String::from_str(&env, "x"))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::Unauthorized);
+        assert!(!market.voided(&POLL));
+    }
+
+    #[test]
+    fn admin_verify_rejects_non_admin() {
+        let (env, _, oracle, _) = setup();
+        vote(&env, &oracle, 7, 3, 0);
+
+        let err = oracle
+            .try_admin_verify(&Address::generate(&env), &POLL, &true, &String::from_str(&env, "x"))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::Unauthorized);
+    }
+}
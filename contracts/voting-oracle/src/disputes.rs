@@ -0,0 +1,531 @@
+use soroban_sdk::{token, Address, Env, String, Symbol};
+use predictx_shared::{
+    pay_treasury, Dispute, FeeSource, OracleError, Poll, PollOutcome, PollStatus,
+    DISPUTE_FEE_AMOUNT, DISPUTE_WINDOW_SECS, MULTI_SIG_REQUIRED,
+};
+use crate::{
+    DataKey, get_market, is_registered_admin, market::PredictionMarketClient, registered_admins,
+    rewards,
+};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+pub fn get_dispute_fee(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeFee)
+        .unwrap_or(DISPUTE_FEE_AMOUNT)
+}
+
+pub fn get_dispute(env: &Env, poll_id: u64) -> Result<Dispute, OracleError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(poll_id))
+        .ok_or(OracleError::DisputeNotFound)
+}
+
+fn save_dispute(env: &Env, dispute: &Dispute) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(dispute.poll_id), dispute);
+}
+
+// ── Disputes ──────────────────────────────────────────────────────────────────
+
+/// Challenge a resolved poll's outcome.
+///
+/// Must be raised within `DISPUTE_WINDOW_SECS` of resolution. The dispute fee
+/// is escrowed in this contract and the poll is frozen in `PredictionMarket`
+/// (status `Disputed`) so no winnings can be claimed until admins settle it.
+/// A poll can be disputed at most once, and only while enough admins are
+/// registered to reach `MULTI_SIG_REQUIRED`.
+pub fn initiate_dispute(
+    env: &Env,
+    initiator: Address,
+    poll_id: u64,
+    evidence_hash: String,
+) -> Result<Dispute, OracleError> {
+    initiator.require_auth();
+
+    if env.storage().persistent().has(&DataKey::Dispute(poll_id)) {
+        return Err(OracleError::DisputeAlreadyOpen);
+    }
+    if registered_admins(env)?.len() < MULTI_SIG_REQUIRED {
+        return Err(OracleError::InsufficientAdminApprovals);
+    }
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    let poll: Poll = market.get_poll(&poll_id);
+    if poll.status != PollStatus::Resolved {
+        return Err(OracleError::PollNotResolved);
+    }
+
+    let now = env.ledger().timestamp();
+    if now >= poll.resolution_time + DISPUTE_WINDOW_SECS {
+        return Err(OracleError::DisputeWindowClosed);
+    }
+
+    let fee = get_dispute_fee(env);
+    let token = token::Client::new(env, &market.get_token_address());
+    if token.balance(&initiator) < fee {
+        return Err(OracleError::DisputeFeeRequired);
+    }
+    token.transfer(&initiator, &env.current_contract_address(), &fee);
+
+    market.mark_disputed(&poll_id);
+
+    let dispute = Dispute {
+        poll_id,
+        initiator: initiator.clone(),
+        evidence_hash,
+        dispute_fee: fee,
+        original_outcome: poll.outcome,
+        proposed_outcome: PollOutcome::Pending,
+        admin_approvals: 0,
+        required_approvals: MULTI_SIG_REQUIRED,
+        resolved: false,
+        initiated_at: now,
+    };
+    save_dispute(env, &dispute);
+
+    env.events().publish(
+        (Symbol::new(env, "DisputeInitiated"), poll_id, initiator),
+        fee,
+    );
+
+    Ok(dispute)
+}
+
+/// Record one admin's sign-off on the final outcome of a disputed poll: a
+/// winning outcome index, or `Void` for no winner.
+///
+/// Each admin's choice is kept under `AdminApproval`, and an admin may switch
+/// to another outcome until the dispute settles, so a split board is never
+/// stuck. Once `required_approvals` admins agree on one outcome, the poll is
+/// re-resolved in `PredictionMarket` and the escrowed fee is settled: refunded
+/// to the initiator if the outcome changed, otherwise sent to the treasury.
+pub fn approve_dispute_resolution(
+    env: &Env,
+    admin: Address,
+    poll_id: u64,
+    outcome: PollOutcome,
+) -> Result<Dispute, OracleError> {
+    admin.require_auth();
+    if !is_registered_admin(env, &admin) {
+        return Err(OracleError::Unauthorized);
+    }
+
+    let mut dispute = get_dispute(env, poll_id)?;
+    if dispute.resolved {
+        return Err(OracleError::PollAlreadyResolved);
+    }
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    match outcome {
+        PollOutcome::Winner(index) if index < market.get_poll(&poll_id).outcomes.len() => {}
+        PollOutcome::Void => {}
+        _ => return Err(OracleError::InvalidOutcome),
+    }
+
+    let approval_key = DataKey::AdminApproval(poll_id, admin.clone());
+    match env.storage().persistent().get::<_, PollOutcome>(&approval_key) {
+        Some(previous) if previous == outcome => return Err(OracleError::AlreadyApproved),
+        Some(_) => {}
+        None => dispute.admin_approvals += 1,
+    }
+    env.storage().persistent().set(&approval_key, &outcome);
+
+    let approvals = approvals_for(env, poll_id, outcome)?;
+    env.events().publish(
+        (Symbol::new(env, "DisputeApproved"), poll_id, admin),
+        (outcome, approvals),
+    );
+
+    if approvals >= dispute.required_approvals {
+        settle(env, &market, &mut dispute, outcome);
+    }
+    save_dispute(env, &dispute);
+
+    Ok(dispute)
+}
+
+/// Number of registered admins whose approval on a dispute names `outcome`.
+fn approvals_for(env: &Env, poll_id: u64, outcome: PollOutcome) -> Result<u32, OracleError> {
+    let mut approvals = 0;
+    for admin in registered_admins(env)?.iter() {
+        let choice: Option<PollOutcome> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AdminApproval(poll_id, admin));
+        if choice == Some(outcome) {
+            approvals += 1;
+        }
+    }
+    Ok(approvals)
+}
+
+/// Push the multi-sig outcome into the market, settle the voter reward pool
+/// against it and settle the escrowed fee.
+fn settle(env: &Env, market: &PredictionMarketClient, dispute: &mut Dispute, outcome: PollOutcome) {
+    match outcome {
+        PollOutcome::Winner(index) => market.resolve_poll_outcome(&dispute.poll_id, &index),
+        _ => market.void_poll(&dispute.poll_id, &false),
+    }
+    rewards::reconcile_pool(env, market, dispute.poll_id);
+
+    let upheld = outcome != dispute.original_outcome;
+    if dispute.dispute_fee > 0 {
+        let token = market.get_token_address();
+        if upheld {
+            token::Client::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &dispute.initiator,
+                &dispute.dispute_fee,
+            );
+        } else {
+            let treasury = market.get_treasury_address();
+            pay_treasury(env, &token, &treasury, dispute.dispute_fee, FeeSource::DisputeFee);
+        }
+    }
+    dispute.proposed_outcome = outcome;
+    dispute.resolved = true;
+
+    env.events().publish(
+        (Symbol::new(env, "DisputeResolved"), dispute.poll_id),
+        (outcome, upheld),
+    );
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String, Vec,
+    };
+    use predictx_shared::{
+        FeeSource, OracleError, Poll, PollCategory, PollOutcome, PollStatus, VoteChoice,
+        DISPUTE_FEE_AMOUNT, DISPUTE_WINDOW_SECS,
+    };
+    use predictx_shared::testutils::{MockTreasury, MockTreasuryClient};
+    use crate::testutils::{MockMarket, MockMarketClient};
+    use crate::{VotingOracle, VotingOracleClient};
+
+    const RESOLVED_AT: u64 = 1_000_000;
+    const POLL: u64 = 1;
+
+    struct TestSetup<'a> {
+        env: Env,
+        admins: [Address; 3],
+        oracle_id: Address,
+        oracle: VotingOracleClient<'a>,
+        market: MockMarketClient<'a>,
+        token: token::Client<'a>,
+        treasury: MockTreasuryClient<'a>,
+    }
+
+    fn setup() -> TestSetup<'static> {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = RESOLVED_AT);
+
+        let token_id = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+        let token = token::Client::new(&env, &token_id);
+        let treasury = MockTreasury::register(&env, &token_id);
+
+        let market = MockMarket::register(&env);
+        market.set_token(&token_id, &treasury.address);
+        market.set_poll(&resolved_poll(&env, 2, PollOutcome::Winner(0)));
+
+        let admins = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let oracle_id = env.register(VotingOracle, ());
+        let oracle = VotingOracleClient::new(&env, &oracle_id);
+        oracle.initialize(&admins[0]);
+        oracle.set_prediction_market(&market.address);
+        oracle.add_admin(&admins[0], &admins[1]);
+        oracle.add_admin(&admins[0], &admins[2]);
+        market.set_oracle(&oracle_id);
+
+        TestSetup { env, admins, oracle_id, oracle, market, token, treasury }
+    }
+
+    /// `POLL` with `outcome_count` options, resolved to `outcome` at `RESOLVED_AT`.
+    fn resolved_poll(env: &Env, outcome_count: u32, outcome: PollOutcome) -> Poll {
+        let mut outcomes = Vec::new(env);
+        let mut outcome_pools = Vec::new(env);
+        let mut outcome_counts = Vec::new(env);
+        for _ in 0..outcome_count {
+            outcomes.push_back(String::from_str(env, "Option"));
+            outcome_pools.push_back(0);
+            outcome_counts.push_back(0);
+        }
+        Poll {
+            poll_id: POLL,
+            match_id: 1,
+            creator: Address::generate(env),
+            question: String::from_str(env, "Will Palmer score?"),
+            category: PollCategory::PlayerEvent,
+            lock_time: RESOLVED_AT - 10_000,
+            outcomes,
+            outcome_pools,
+            outcome_counts,
+            status: PollStatus::Resolved,
+            outcome,
+            resolution_time: RESOLVED_AT,
+            created_at: RESOLVED_AT - 20_000,
+        }
+    }
+
+    fn funded_user(s: &TestSetup) -> Address {
+        let user = Address::generate(&s.env);
+        token::StellarAssetClient::new(&s.env, &s.token.address).mint(&user, &DISPUTE_FEE_AMOUNT);
+        user
+    }
+
+    fn evidence(s: &TestSetup) -> String {
+        String::from_str(&s.env, "ipfs://QmEvidence")
+    }
+
+    fn approve_all(s: &TestSetup, outcome: PollOutcome) {
+        for admin in s.admins.iter() {
+            s.oracle.approve_dispute_resolution(admin, &POLL, &outcome);
+        }
+    }
+
+    /// Three Yes ballots, and the voter reward pool the market's crank carved
+    /// and recorded when the vote resolved the poll to Yes.
+    fn carved_yes_vote(s: &TestSetup, reward_pool: i128) {
+        s.oracle.start_voting(&POLL);
+        for _ in 0..3 {
+            s.oracle.cast_vote(&Address::generate(&s.env), &POLL, &VoteChoice::Yes);
+        }
+        s.market.set_carved(&POLL, &reward_pool);
+        s.oracle.record_voter_reward_pool(&POLL, &reward_pool);
+        token::StellarAssetClient::new(&s.env, &s.token.address).mint(&s.oracle_id, &reward_pool);
+    }
+
+    // ── Initiation ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn initiate_dispute_escrows_fee_and_freezes_poll() {
+        let s = setup();
+        let user = funded_user(&s);
+
+        let dispute = s.oracle.initiate_dispute(&user, &POLL, &evidence(&s));
+
+        assert_eq!(dispute.dispute_fee, DISPUTE_FEE_AMOUNT);
+        assert_eq!(dispute.original_outcome, PollOutcome::Winner(0));
+        assert_eq!(dispute.proposed_outcome, PollOutcome::Pending);
+        assert_eq!(dispute.required_approvals, 3);
+        assert_eq!(s.token.balance(&user), 0);
+        assert_eq!(s.token.balance(&s.oracle_id), DISPUTE_FEE_AMOUNT);
+        assert_eq!(s.market.get_poll(&POLL).status, PollStatus::Disputed);
+    }
+
+    #[test]
+    fn initiate_dispute_requires_fee() {
+        let s = setup();
+        let err = s
+            .oracle
+            .try_initiate_dispute(&Address::generate(&s.env), &POLL, &evidence(&s))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::DisputeFeeRequired);
+    }
+
+    #[test]
+    fn initiate_dispute_rejects_after_window() {
+        let s = setup();
+        let user = funded_user(&s);
+        s.env.ledger().with_mut(|l| l.timestamp = RESOLVED_AT + DISPUTE_WINDOW_SECS);
+
+        let err = s.oracle.try_initiate_dispute(&user, &POLL, &evidence(&s)).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::DisputeWindowClosed);
+    }
+
+    #[test]
+    fn initiate_dispute_rejects_second_dispute() {
+        let s = setup();
+        s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+
+        let err = s
+            .oracle
+            .try_initiate_dispute(&funded_user(&s), &POLL, &evidence(&s))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::DisputeAlreadyOpen);
+    }
+
+    #[test]
+    fn initiate_dispute_needs_enough_admins_to_settle() {
+        let s = setup();
+        let oracle = VotingOracleClient::new(&s.env, &s.env.register(VotingOracle, ()));
+        oracle.initialize(&s.admins[0]);
+        oracle.set_prediction_market(&s.market.address);
+
+        let err = oracle
+            .try_initiate_dispute(&funded_user(&s), &POLL, &evidence(&s))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::InsufficientAdminApprovals);
+    }
+
+    // ── Settlement ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn upheld_dispute_flips_outcome_and_refunds_fee() {
+        let s = setup();
+        let user = funded_user(&s);
+        s.oracle.initiate_dispute(&user, &POLL, &evidence(&s));
+
+        s.oracle.approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Winner(1));
+        s.oracle.approve_dispute_resolution(&s.admins[1], &POLL, &PollOutcome::Winner(1));
+        assert_eq!(s.market.get_poll(&POLL).status, PollStatus::Disputed);
+
+        let dispute =
+            s.oracle.approve_dispute_resolution(&s.admins[2], &POLL, &PollOutcome::Winner(1));
+
+        assert!(dispute.resolved);
+        assert_eq!(dispute.proposed_outcome, PollOutcome::Winner(1));
+        let poll = s.market.get_poll(&POLL);
+        assert_eq!(poll.status, PollStatus::Resolved);
+        assert_eq!(poll.outcome, PollOutcome::Winner(1));
+        assert_eq!(s.token.balance(&user), DISPUTE_FEE_AMOUNT);
+        assert_eq!(s.token.balance(&s.oracle_id), 0);
+    }
+
+    #[test]
+    fn rejected_dispute_sends_fee_to_treasury() {
+        let s = setup();
+        let user = funded_user(&s);
+        s.oracle.initiate_dispute(&user, &POLL, &evidence(&s));
+
+        approve_all(&s, PollOutcome::Winner(0));
+
+        assert_eq!(s.market.get_poll(&POLL).outcome, PollOutcome::Winner(0));
+        assert_eq!(s.token.balance(&user), 0);
+        assert_eq!(s.token.balance(&s.treasury.address), DISPUTE_FEE_AMOUNT);
+        assert_eq!(s.treasury.fees_collected(&FeeSource::DisputeFee), DISPUTE_FEE_AMOUNT);
+    }
+
+    #[test]
+    fn overturned_vote_sends_unearned_reward_pool_to_treasury() {
+        let s = setup();
+        let reward_pool: i128 = 30_000_000;
+        carved_yes_vote(&s, reward_pool);
+
+        let user = funded_user(&s);
+        s.oracle.initiate_dispute(&user, &POLL, &evidence(&s));
+        approve_all(&s, PollOutcome::Winner(1));
+
+        assert_eq!(s.treasury.fees_collected(&FeeSource::UnearnedVoterRewards), reward_pool);
+        assert_eq!(s.oracle.get_voting_stats(&POLL).reward_pool, 0);
+        assert_eq!(s.token.balance(&user), DISPUTE_FEE_AMOUNT);
+        assert_eq!(s.token.balance(&s.oracle_id), 0);
+    }
+
+    #[test]
+    fn voided_dispute_returns_reward_pool_to_market() {
+        let s = setup();
+        let reward_pool: i128 = 30_000_000;
+        carved_yes_vote(&s, reward_pool);
+
+        let user = funded_user(&s);
+        s.oracle.initiate_dispute(&user, &POLL, &evidence(&s));
+        approve_all(&s, PollOutcome::Void);
+
+        assert_eq!(s.market.get_poll(&POLL).outcome, PollOutcome::Void);
+        assert!(!s.market.slashed(&POLL));
+        assert_eq!(s.token.balance(&s.market.address), reward_pool);
+        assert_eq!(s.oracle.get_voting_stats(&POLL).reward_pool, 0);
+        assert_eq!(s.treasury.fees_collected(&FeeSource::UnearnedVoterRewards), 0);
+        assert_eq!(s.token.balance(&user), DISPUTE_FEE_AMOUNT);
+    }
+
+    #[test]
+    fn categorical_poll_can_be_disputed() {
+        let s = setup();
+        s.market.set_poll(&resolved_poll(&s.env, 3, PollOutcome::Winner(2)));
+        s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+
+        let err = s
+            .oracle
+            .try_approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Winner(3))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::InvalidOutcome);
+
+        approve_all(&s, PollOutcome::Winner(0));
+        assert_eq!(s.market.get_poll(&POLL).outcome, PollOutcome::Winner(0));
+    }
+
+    #[test]
+    fn voided_poll_can_be_disputed() {
+        let s = setup();
+        s.market.set_poll(&resolved_poll(&s.env, 2, PollOutcome::Void));
+        let dispute = s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+        assert_eq!(dispute.original_outcome, PollOutcome::Void);
+
+        approve_all(&s, PollOutcome::Winner(1));
+        assert_eq!(s.market.get_poll(&POLL).outcome, PollOutcome::Winner(1));
+    }
+
+    // ── Approvals ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn approval_counts_distinct_admins_only() {
+        let s = setup();
+        s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+        s.oracle.approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Winner(1));
+
+        let err = s
+            .oracle
+            .try_approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Winner(1))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::AlreadyApproved);
+        assert_eq!(s.oracle.get_dispute(&POLL).admin_approvals, 1);
+    }
+
+    #[test]
+    fn approvals_are_counted_per_outcome() {
+        let s = setup();
+        s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+        s.oracle.approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Winner(1));
+        s.oracle.approve_dispute_resolution(&s.admins[1], &POLL, &PollOutcome::Winner(0));
+        let dispute =
+            s.oracle.approve_dispute_resolution(&s.admins[2], &POLL, &PollOutcome::Winner(1));
+        assert!(!dispute.resolved);
+        assert_eq!(dispute.admin_approvals, 3);
+
+        // The dissenting admin comes round, giving No three approvals
+        let dispute =
+            s.oracle.approve_dispute_resolution(&s.admins[1], &POLL, &PollOutcome::Winner(1));
+        assert!(dispute.resolved);
+        assert_eq!(dispute.admin_approvals, 3);
+        assert_eq!(s.market.get_poll(&POLL).outcome, PollOutcome::Winner(1));
+    }
+
+    #[test]
+    fn approval_rejects_unregistered_admin_and_pending_outcome() {
+        let s = setup();
+        s.oracle.initiate_dispute(&funded_user(&s), &POLL, &evidence(&s));
+
+        let err = s
+            .oracle
+            .try_approve_dispute_resolution(&Address::generate(&s.env), &POLL, &PollOutcome::Winner(1))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::Unauthorized);
+
+        let err = s
+            .oracle
+            .try_approve_dispute_resolution(&s.admins[0], &POLL, &PollOutcome::Pending)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::InvalidOutcome);
+    }
+}
@@ -1,37 +1,92 @@
 #![no_std]
 
-use predictx_shared::{PredictXError, PollStatus};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+mod consensus;
+mod disputes;
+mod market;
+mod rewards;
+#[cfg(test)]
+mod testutils;
+mod voting;
+
+pub use consensus::{AdminVerification, Consensus};
+
+use predictx_shared::{Dispute, OracleError, PollOutcome, UserStats, VoteChoice, VoteTally};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
 
 #[contract]
 pub struct VotingOracle;
 
 #[contracttype]
 #[derive(Clone)]
-struct StoredPollStatus {
-    status: PollStatus,
-    updated_at: u64,
-}
-
-#[contracttype]
-#[derive(Clone)]
-enum DataKey {
+pub(crate) enum DataKey {
     Admin,
-    PollStatus(u64),
+    AdminList,
+    PredictionMarket,
+    DisputeFee,
+    // ── voting keys (persistent) ──────────────────────────────────────────────
+    VoteTally(u64),
+    HasVoted(u64, Address),
+    Ballot(u64, Address),
+    // ── resolution keys ───────────────────────────────────────────────────────
+    AdminVerification(u64),
+    // ── dispute keys (persistent) ─────────────────────────────────────────────
+    Dispute(u64),
+    AdminApproval(u64, Address),
+    // ── reward keys (persistent) ──────────────────────────────────────────────
+    VoterReward(u64, Address),
+    UserStats(Address),
 }
 
-fn get_admin(env: &Env) -> Result<Address, PredictXError> {
+fn get_admin(env: &Env) -> Result<Address, OracleError> {
     env.storage()
         .instance()
         .get(&DataKey::Admin)
-        .ok_or(PredictXError::NotInitialized)
+        .ok_or(OracleError::NotInitialized)
+}
+
+pub(crate) fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
+    caller.require_auth();
+    if *caller != get_admin(env)? {
+        return Err(OracleError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn get_admin_list(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AdminList)
+        .unwrap_or(Vec::new(env))
+}
+
+/// The primary admin followed by every admin added with `add_admin`.
+pub(crate) fn registered_admins(env: &Env) -> Result<Vec<Address>, OracleError> {
+    let mut admins = Vec::new(env);
+    admins.push_back(get_admin(env)?);
+    admins.append(&get_admin_list(env));
+    Ok(admins)
+}
+
+/// The primary admin plus every admin added with `add_admin`.
+pub(crate) fn is_registered_admin(env: &Env, who: &Address) -> bool {
+    match get_admin(env) {
+        Ok(admin) if admin == *who => true,
+        _ => get_admin_list(env).contains(who),
+    }
+}
+
+pub(crate) fn get_market(env: &Env) -> Result<Address, OracleError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PredictionMarket)
+        .ok_or(OracleError::NotInitialized)
 }
 
 #[contractimpl]
 impl VotingOracle {
-    pub fn initialize(env: Env, admin: Address) -> Result<(), PredictXError> {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), OracleError> {
         if env.storage().instance().has(&DataKey::Admin) {
-            return Err(PredictXError::AlreadyInitialized);
+            return Err(OracleError::AlreadyInitialized);
         }
         admin.require_auth();
 
@@ -39,69 +94,173 @@ impl VotingOracle {
         Ok(())
     }
 
-    pub fn admin(env: Env) -> Result<Address, PredictXError> {
+    pub fn admin(env: Env) -> Result<Address, OracleError> {
         get_admin(&env)
     }
 
-    /// Placeholder oracle state setter.
-    ///
-    /// This exists only to validate cross-contract invocation patterns during
-    /// Phase 1 scaffolding.
-    pub fn set_poll_status(env: Env, poll_id: u64, status: PollStatus) -> Result<(), PredictXError> {
+    /// Point the oracle at the `PredictionMarket` it serves. Admin only.
+    pub fn set_prediction_market(env: Env, market: Address) -> Result<(), OracleError> {
         let admin = get_admin(&env)?;
         admin.require_auth();
+        env.storage().instance().set(&DataKey::PredictionMarket, &market);
+        Ok(())
+    }
 
-        let stored = StoredPollStatus {
-            status,
-            updated_at: env.ledger().timestamp(),
-        };
+    pub fn prediction_market(env: Env) -> Result<Address, OracleError> {
+        get_market(&env)
+    }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::PollStatus(poll_id), &stored);
+    /// Register an additional admin for multi-sig dispute approvals.
+    pub fn add_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+        if is_registered_admin(&env, &new_admin) {
+            return Err(OracleError::AdminAlreadyRegistered);
+        }
+        let mut admins = get_admin_list(&env);
+        admins.push_back(new_admin.clone());
+        env.storage().instance().set(&DataKey::AdminList, &admins);
+        env.events().publish((Symbol::new(&env, "AdminAdded"),), new_admin);
         Ok(())
     }
 
-    /// Placeholder oracle query used by `PredictionMarket`.
-    pub fn get_poll_status(env: Env, poll_id: u64) -> PollStatus {
-        let stored: Option<StoredPollStatus> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PollStatus(poll_id));
+    /// All registered admins, primary admin first.
+    pub fn get_admins(env: Env) -> Result<Vec<Address>, OracleError> {
+        registered_admins(&env)
+    }
+
+    pub fn set_dispute_fee(env: Env, admin: Address, fee: i128) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+        if fee < 0 {
+            return Err(OracleError::StakeAmountZero);
+        }
+        env.storage().instance().set(&DataKey::DisputeFee, &fee);
+        Ok(())
+    }
 
-        stored.map(|s| s.status).unwrap_or(PollStatus::Active)
+    pub fn get_dispute_fee(env: Env) -> i128 {
+        disputes::get_dispute_fee(&env)
     }
 
-    pub fn get_poll_status_updated_at(env: Env, poll_id: u64) -> u64 {
-        let stored: Option<StoredPollStatus> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PollStatus(poll_id));
+    // ── Community voting ──────────────────────────────────────────────────────
 
-        stored.map(|s| s.updated_at).unwrap_or(0)
+    pub fn start_voting(env: Env, poll_id: u64) -> Result<VoteTally, OracleError> {
+        voting::start_voting(&env, poll_id)
     }
-}
 
-#[cfg(test)]
-extern crate std;
+    pub fn cast_vote(
+        env: Env,
+        voter: Address,
+        poll_id: u64,
+        choice: VoteChoice,
+    ) -> Result<VoteTally, OracleError> {
+        voting::cast_vote(&env, voter, poll_id, choice)
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
+    pub fn get_voting_stats(env: Env, poll_id: u64) -> Result<VoteTally, OracleError> {
+        voting::get_voting_stats(&env, poll_id)
+    }
 
-    #[test]
-    fn set_and_get_status() {
-        let env = Env::default();
-        env.mock_all_auths();
+    pub fn can_vote(env: Env, poll_id: u64, user: Address) -> bool {
+        voting::can_vote(&env, poll_id, &user)
+    }
 
-        let contract_id = env.register(VotingOracle, ());
-        let client = VotingOracleClient::new(&env, &contract_id);
+    pub fn get_ballot(env: Env, poll_id: u64, voter: Address) -> Option<VoteChoice> {
+        voting::get_ballot(&env, poll_id, &voter)
+    }
 
-        let admin = Address::generate(&env);
-        client.initialize(&admin);
+    // ── Resolution ────────────────────────────────────────────────────────────
+
+    pub fn get_consensus(env: Env, poll_id: u64) -> Result<Consensus, OracleError> {
+        consensus::get_consensus(&env, poll_id)
+    }
+
+    pub fn finalize_voting(env: Env, poll_id: u64) -> Result<Consensus, OracleError> {
+        consensus::finalize_voting(&env, poll_id)
+    }
+
+    pub fn admin_verify(
+        env: Env,
+        admin: Address,
+        poll_id: u64,
+        outcome: bool,
+        reasoning_hash: String,
+    ) -> Result<(), OracleError> {
+        consensus::admin_verify(&env, admin, poll_id, outcome, reasoning_hash)
+    }
+
+    /// Name the winning outcome of a categorical poll in admin review.
+    pub fn admin_resolve_outcome(
+        env: Env,
+        admin: Address,
+        poll_id: u64,
+        outcome_index: u32,
+        reasoning_hash: String,
+    ) -> Result<(), OracleError> {
+        consensus::admin_resolve_outcome(&env, admin, poll_id, outcome_index, reasoning_hash)
+    }
+
+    /// Void a poll the community could not settle; every stake is refunded.
+    pub fn admin_void(
+        env: Env,
+        admin: Address,
+        poll_id: u64,
+        reasoning_hash: String,
+    ) -> Result<(), OracleError> {
+        consensus::admin_void(&env, admin, poll_id, reasoning_hash)
+    }
+
+    pub fn get_admin_verification(env: Env, poll_id: u64) -> Option<AdminVerification> {
+        consensus::get_admin_verification(&env, poll_id)
+    }
+
+    // ── Disputes ──────────────────────────────────────────────────────────────
+
+    pub fn initiate_dispute(
+        env: Env,
+        initiator: Address,
+        poll_id: u64,
+        evidence_hash: String,
+    ) -> Result<Dispute, OracleError> {
+        disputes::initiate_dispute(&env, initiator, poll_id, evidence_hash)
+    }
+
+    pub fn approve_dispute_resolution(
+        env: Env,
+        admin: Address,
+        poll_id: u64,
+        outcome: PollOutcome,
+    ) -> Result<Dispute, OracleError> {
+        disputes::approve_dispute_resolution(&env, admin, poll_id, outcome)
+    }
+
+    pub fn get_dispute(env: Env, poll_id: u64) -> Result<Dispute, OracleError> {
+        disputes::get_dispute(&env, poll_id)
+    }
+
+    // ── Voter rewards ─────────────────────────────────────────────────────────
+
+    pub fn claim_voter_reward(env: Env, voter: Address, poll_id: u64) -> Result<i128, OracleError> {
+        rewards::claim_voter_reward(&env, voter, poll_id)
+    }
+
+    pub fn claim_voter_rewards(
+        env: Env,
+        voter: Address,
+        poll_ids: Vec<u64>,
+    ) -> Result<i128, OracleError> {
+        rewards::claim_voter_rewards(&env, voter, poll_ids)
+    }
+
+    /// Called by the market when its crank carves a poll's voter reward pool.
+    pub fn record_voter_reward_pool(env: Env, poll_id: u64, amount: i128) -> Result<(), OracleError> {
+        rewards::record_voter_reward_pool(&env, poll_id, amount)
+    }
+
+    pub fn get_voter_reward(env: Env, poll_id: u64, voter: Address) -> Option<i128> {
+        rewards::get_voter_reward(&env, poll_id, &voter)
+    }
 
-        client.set_poll_status(&42_u64, &PollStatus::Resolved);
-        assert_eq!(client.get_poll_status(&42_u64), PollStatus::Resolved);
+    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
+        rewards::load_user_stats(&env, &user)
     }
 }
@@ -0,0 +1,31 @@
+use predictx_shared::{Poll, PollOutcome, StakeSide};
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The slice of the `PredictionMarket` interface this oracle calls into.
+///
+/// `PredictionMarket` already `contractimport!`s this contract's WASM, so the
+/// reverse direction is declared by hand to avoid a build cycle.
+#[allow(dead_code)]
+#[contractclient(name = "PredictionMarketClient")]
+pub trait PredictionMarketInterface {
+    fn has_user_staked(env: Env, poll_id: u64, user: Address) -> bool;
+    fn resolve_poll(env: Env, poll_id: u64, outcome: bool);
+    fn resolve_poll_outcome(env: Env, poll_id: u64, outcome_index: u32);
+    fn void_poll(env: Env, poll_id: u64, slash: bool);
+    fn escalate_poll(env: Env, poll_id: u64);
+    fn mark_disputed(env: Env, poll_id: u64);
+    fn get_poll(env: Env, poll_id: u64) -> Poll;
+    fn get_token_address(env: Env) -> Address;
+    fn get_treasury_address(env: Env) -> Address;
+    fn fund_voter_rewards(env: Env, poll_id: u64) -> i128;
+    fn get_voter_reward_pool(env: Env, poll_id: u64) -> i128;
+}
+
+/// Result of a resolved binary poll: `Some(true)` if Yes won, `Some(false)`
+/// if No won, `None` while unresolved or if the poll was voided.
+pub(crate) fn binary_outcome(poll: &Poll) -> Option<bool> {
+    match poll.outcome {
+        PollOutcome::Winner(index) => Some(index == StakeSide::Yes as u32),
+        PollOutcome::Pending | PollOutcome::Void => None,
+    }
+}
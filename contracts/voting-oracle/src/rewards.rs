@@ -0,0 +1,380 @@
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
+use predictx_shared::{
+    pay_treasury, FeeSource, PollStatus, OracleError, UserStats, VoteChoice, VoteTally,
+    DISPUTE_WINDOW_SECS,
+};
+use crate::{DataKey, get_market, market::{self, PredictionMarketClient}, voting};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Number of voters whose ballot matches `outcome`.
+fn correct_votes(tally: &VoteTally, outcome: bool) -> u32 {
+    if outcome {
+        tally.yes_votes
+    } else {
+        tally.no_votes
+    }
+}
+
+pub(crate) fn load_user_stats(env: &Env, user: &Address) -> UserStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserStats(user.clone()))
+        .unwrap_or(UserStats {
+            total_staked: 0,
+            total_won: 0,
+            total_lost: 0,
+            polls_participated: 0,
+            polls_won: 0,
+            polls_lost: 0,
+            votes_cast: 0,
+            voting_rewards_earned: 0,
+        })
+}
+
+pub(crate) fn save_user_stats(env: &Env, user: &Address, stats: &UserStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserStats(user.clone()), stats);
+}
+
+/// Ask the market to carve the voter reward share of a just-resolved poll,
+/// provided at least one voter backed `outcome`. Otherwise the share stays
+/// with the winners.
+pub(crate) fn fund_if_earned(
+    env: &Env,
+    market: &PredictionMarketClient,
+    poll_id: u64,
+    outcome: bool,
+) {
+    let mut tally = match voting::load_tally(env, poll_id) {
+        Ok(t) => t,
+        Err(_) => return,
+    };
+    if correct_votes(&tally, outcome) == 0 {
+        return;
+    }
+    tally.reward_pool = market.fund_voter_rewards(&poll_id);
+    voting::save_tally(env, &tally);
+}
+
+/// Record the voter reward pool the market carved when its own crank settled
+/// a poll's vote. Only the `PredictionMarket` may call this; every other
+/// resolution goes through the oracle, which funds the tally itself.
+pub fn record_voter_reward_pool(env: &Env, poll_id: u64, amount: i128) -> Result<(), OracleError> {
+    get_market(env)?.require_auth();
+    let mut tally = voting::load_tally(env, poll_id)?;
+    tally.reward_pool = amount;
+    voting::save_tally(env, &tally);
+    Ok(())
+}
+
+/// Bring a re-resolved poll's voter reward pool in line with its final
+/// outcome, as happens when a dispute overturns the vote:
+/// - if the poll now refunds every stake, the market has written the pool off
+///   and it goes back to the market to fund the refunds;
+/// - if voters backed the final outcome, the pool is funded as usual;
+/// - otherwise nobody could ever claim it, so it goes to the treasury.
+pub(crate) fn reconcile_pool(env: &Env, market: &PredictionMarketClient, poll_id: u64) {
+    let mut tally = match voting::load_tally(env, poll_id) {
+        Ok(t) => t,
+        Err(_) => return,
+    };
+    let token = market.get_token_address();
+
+    if tally.reward_pool > 0 && market.get_voter_reward_pool(&poll_id) == 0 {
+        token::Client::new(env, &token).transfer(
+            &env.current_contract_address(),
+            &market.address,
+            &tally.reward_pool,
+        );
+        env.events().publish(
+            (Symbol::new(env, "VoterRewardsReturned"), poll_id),
+            tally.reward_pool,
+        );
+        tally.reward_pool = 0;
+        voting::save_tally(env, &tally);
+    }
+
+    let earned = market::binary_outcome(&market.get_poll(&poll_id))
+        .map(|outcome| correct_votes(&tally, outcome) > 0)
+        .unwrap_or(false);
+    if earned {
+        tally.reward_pool = market.fund_voter_rewards(&poll_id);
+        voting::save_tally(env, &tally);
+        return;
+    }
+    if tally.reward_pool == 0 {
+        return;
+    }
+
+    let pool = tally.reward_pool;
+    let treasury = market.get_treasury_address();
+    pay_treasury(env, &token, &treasury, pool, FeeSource::UnearnedVoterRewards);
+    tally.reward_pool = 0;
+    voting::save_tally(env, &tally);
+
+    env.events().publish(
+        (Symbol::new(env, "VoterRewardsForfeited"), poll_id),
+        pool,
+    );
+}
+
+// ── Claims ────────────────────────────────────────────────────────────────────
+
+/// Pay `voter` their share of a poll's voter reward pool.
+///
+/// The pool is split evenly between every voter whose ballot matched the final
+/// outcome. Claims follow the same freeze as winnings: they open once the
+/// dispute window has passed and stay closed while a dispute is open.
+pub fn claim_voter_reward(env: &Env, voter: Address, poll_id: u64) -> Result<i128, OracleError> {
+    voter.require_auth();
+    claim(env, &voter, poll_id)
+}
+
+/// Claim voter rewards on several polls at once. Returns the total paid.
+pub fn claim_voter_rewards(
+    env: &Env,
+    voter: Address,
+    poll_ids: Vec<u64>,
+) -> Result<i128, OracleError> {
+    voter.require_auth();
+    let mut total = 0;
+    for poll_id in poll_ids.iter() {
+        total += claim(env, &voter, poll_id)?;
+    }
+    Ok(total)
+}
+
+fn claim(env: &Env, voter: &Address, poll_id: u64) -> Result<i128, OracleError> {
+    let reward_key = DataKey::VoterReward(poll_id, voter.clone());
+    if env.storage().persistent().has(&reward_key) {
+        return Err(OracleError::AlreadyClaimed);
+    }
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    let poll = market.get_poll(&poll_id);
+    match poll.status {
+        PollStatus::Resolved => {}
+        PollStatus::Disputed => return Err(OracleError::DisputeAlreadyOpen),
+        _ => return Err(OracleError::PollNotResolved),
+    }
+    let outcome = market::binary_outcome(&poll).ok_or(OracleError::PollNotResolved)?;
+    if env.ledger().timestamp() < poll.resolution_time + DISPUTE_WINDOW_SECS {
+        return Err(OracleError::DisputeWindowOpen);
+    }
+
+    let backed_outcome = match voting::get_ballot(env, poll_id, voter) {
+        None => return Err(OracleError::NotVoter),
+        Some(VoteChoice::Yes) => outcome,
+        Some(VoteChoice::No) => !outcome,
+        Some(VoteChoice::Unclear) => false,
+    };
+    if !backed_outcome {
+        return Err(OracleError::NotOnWinningSide);
+    }
+
+    let tally = voting::load_tally(env, poll_id)?;
+    let reward = tally.reward_pool / correct_votes(&tally, outcome) as i128;
+
+    env.storage().persistent().set(&reward_key, &reward);
+    let mut stats = load_user_stats(env, voter);
+    stats.voting_rewards_earned += reward;
+    save_user_stats(env, voter, &stats);
+
+    if reward > 0 {
+        let token = token::Client::new(env, &market.get_token_address());
+        token.transfer(&env.current_contract_address(), voter, &reward);
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "VoterRewardClaimed"), poll_id, voter.clone()),
+        reward,
+    );
+
+    Ok(reward)
+}
+
+/// Reward already paid to `voter` on a poll, if they have claimed.
+pub fn get_voter_reward(env: &Env, poll_id: u64, voter: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoterReward(poll_id, voter.clone()))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, vec, Address, Env,
+    };
+    use predictx_shared::{OracleError, VoteChoice, DISPUTE_WINDOW_SECS, VOTING_WINDOW_SECS};
+    use crate::testutils::{MockMarket, MockMarketClient};
+    use crate::{VotingOracle, VotingOracleClient};
+
+    const START: u64 = 1_000_000;
+    const POLL: u64 = 1;
+    const REWARD_POOL: i128 = 30_000_000;
+
+    struct TestSetup<'a> {
+        env: Env,
+        oracle_id: Address,
+        oracle: VotingOracleClient<'a>,
+        market: MockMarketClient<'a>,
+        token: token::Client<'a>,
+    }
+
+    fn setup() -> TestSetup<'static> {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = START);
+
+        let token_admin = Address::generate(&env);
+        let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+        let token = token::Client::new(&env, &token_id);
+
+        let market = MockMarket::register(&env);
+        market.set_token(&token_id, &Address::generate(&env));
+        market.set_reward_pool(&REWARD_POOL);
+        token::StellarAssetClient::new(&env, &token_id).mint(&market.address, &REWARD_POOL);
+
+        let oracle_id = env.register(VotingOracle, ());
+        let oracle = VotingOracleClient::new(&env, &oracle_id);
+        oracle.initialize(&Address::generate(&env));
+        oracle.set_prediction_market(&market.address);
+        market.set_oracle(&oracle_id);
+        oracle.start_voting(&POLL);
+
+        TestSetup { env, oracle_id, oracle, market, token }
+    }
+
+    fn vote(s: &TestSetup, choice: VoteChoice) -> Address {
+        let voter = Address::generate(&s.env);
+        s.oracle.cast_vote(&voter, &POLL, &choice);
+        voter
+    }
+
+    /// Close voting, auto-resolve through the oracle and let the dispute window lapse.
+    fn finalize(s: &TestSetup) {
+        s.env.ledger().with_mut(|l| l.timestamp = START + VOTING_WINDOW_SECS);
+        s.oracle.finalize_voting(&POLL);
+        s.env
+            .ledger()
+            .with_mut(|l| l.timestamp = START + VOTING_WINDOW_SECS + DISPUTE_WINDOW_SECS);
+    }
+
+    /// Nine Yes ballots and one No: 90% auto-resolves to Yes.
+    /// Returns one Yes voter and the No voter.
+    fn majority_yes(s: &TestSetup) -> (Address, Address) {
+        let winner = vote(s, VoteChoice::Yes);
+        for _ in 0..8 {
+            vote(s, VoteChoice::Yes);
+        }
+        let loser = vote(s, VoteChoice::No);
+        (winner, loser)
+    }
+
+    #[test]
+    fn finalize_funds_reward_pool() {
+        let s = setup();
+        majority_yes(&s);
+        finalize(&s);
+
+        assert_eq!(s.oracle.get_voting_stats(&POLL).reward_pool, REWARD_POOL);
+        assert_eq!(s.token.balance(&s.oracle_id), REWARD_POOL);
+    }
+
+    #[test]
+    fn pool_recorded_by_market_crank_is_claimable() {
+        let s = setup();
+        let (winner, _) = majority_yes(&s);
+
+        // The crank resolves the poll on the market and carves the pool there.
+        s.market.resolve_poll(&POLL, &true);
+        s.market.set_carved(&POLL, &REWARD_POOL);
+        s.token.transfer(&s.market.address, &s.oracle_id, &REWARD_POOL);
+        s.oracle.record_voter_reward_pool(&POLL, &REWARD_POOL);
+        s.env
+            .ledger()
+            .with_mut(|l| l.timestamp = START + DISPUTE_WINDOW_SECS);
+
+        assert_eq!(s.oracle.claim_voter_reward(&winner, &POLL), REWARD_POOL / 9);
+    }
+
+    #[test]
+    fn correct_voter_receives_equal_share() {
+        let s = setup();
+        let (winner, _) = majority_yes(&s);
+        finalize(&s);
+
+        // 9 Yes voters share the pool.
+        let reward = s.oracle.claim_voter_reward(&winner, &POLL);
+        assert_eq!(reward, REWARD_POOL / 9);
+        assert_eq!(s.token.balance(&winner), REWARD_POOL / 9);
+        assert_eq!(s.oracle.get_voter_reward(&POLL, &winner), Some(REWARD_POOL / 9));
+        assert_eq!(s.oracle.get_user_stats(&winner).voting_rewards_earned, REWARD_POOL / 9);
+    }
+
+    #[test]
+    fn claim_rejects_wrong_ballot_and_non_voter() {
+        let s = setup();
+        let (_, loser) = majority_yes(&s);
+        finalize(&s);
+
+        let err = s.oracle.try_claim_voter_reward(&loser, &POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::NotOnWinningSide);
+
+        let err = s
+            .oracle
+            .try_claim_voter_reward(&Address::generate(&s.env), &POLL)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::NotVoter);
+    }
+
+    #[test]
+    fn claim_rejects_double_claim() {
+        let s = setup();
+        let (winner, _) = majority_yes(&s);
+        finalize(&s);
+
+        s.oracle.claim_voter_reward(&winner, &POLL);
+        let err = s.oracle.try_claim_voter_reward(&winner, &POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn claim_waits_for_dispute_window() {
+        let s = setup();
+        let (winner, _) = majority_yes(&s);
+        s.env.ledger().with_mut(|l| l.timestamp = START + VOTING_WINDOW_SECS);
+        s.oracle.finalize_voting(&POLL);
+
+        let err = s.oracle.try_claim_voter_reward(&winner, &POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::DisputeWindowOpen);
+    }
+
+    #[test]
+    fn claim_rejects_unresolved_poll() {
+        let s = setup();
+        let winner = vote(&s, VoteChoice::Yes);
+
+        let err = s.oracle.try_claim_voter_reward(&winner, &POLL).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::PollNotResolved);
+    }
+
+    #[test]
+    fn batched_claim_sums_rewards() {
+        let s = setup();
+        let (winner, _) = majority_yes(&s);
+        finalize(&s);
+
+        let total = s.oracle.claim_voter_rewards(&winner, &vec![&s.env, POLL]);
+        assert_eq!(total, REWARD_POOL / 9);
+        assert_eq!(s.oracle.get_user_stats(&winner).votes_cast, 1);
+    }
+}
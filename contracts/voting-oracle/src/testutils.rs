@@ -0,0 +1,175 @@
+//! Test doubles shared by the oracle's unit tests.
+
+use soroban_sdk::{contract, contractimpl, contracttype, token, vec, Address, Env, String};
+use predictx_shared::{Poll, PollCategory, PollOutcome, PollStatus};
+
+#[contracttype]
+#[derive(Clone)]
+enum MockKey {
+    Token,
+    Treasury,
+    Oracle,
+    RewardPool,
+    Poll(u64),
+    Staked(u64, Address),
+    Carved(u64),
+    Slashed(u64),
+}
+
+/// `PredictionMarket` stand-in shared by the oracle's tests.
+///
+/// Any poll it has not been given is a Yes/No poll in `Voting`; resolutions,
+/// escalations and disputes are written back onto the stored poll.
+/// `fund_voter_rewards` pays the amount set with `set_reward_pool` to the
+/// oracle, so the mock must hold those tokens.
+#[contract]
+pub struct MockMarket;
+
+impl MockMarket {
+    pub fn register<'a>(env: &Env) -> MockMarketClient<'a> {
+        MockMarketClient::new(env, &env.register(MockMarket, ()))
+    }
+
+    fn settle(env: &Env, poll_id: u64, outcome: PollOutcome) {
+        let mut poll = Self::get_poll(env.clone(), poll_id);
+        poll.status = PollStatus::Resolved;
+        poll.outcome = outcome;
+        poll.resolution_time = env.ledger().timestamp();
+        Self::set_poll(env.clone(), poll);
+    }
+}
+
+#[contractimpl]
+impl MockMarket {
+    // ── Setup ─────────────────────────────────────────────────────────────────
+
+    pub fn set_token(env: Env, token: Address, treasury: Address) {
+        env.storage().instance().set(&MockKey::Token, &token);
+        env.storage().instance().set(&MockKey::Treasury, &treasury);
+    }
+
+    pub fn set_oracle(env: Env, oracle: Address) {
+        env.storage().instance().set(&MockKey::Oracle, &oracle);
+    }
+
+    pub fn set_poll(env: Env, poll: Poll) {
+        env.storage().persistent().set(&MockKey::Poll(poll.poll_id), &poll);
+    }
+
+    pub fn set_staked(env: Env, poll_id: u64, user: Address) {
+        env.storage().persistent().set(&MockKey::Staked(poll_id, user), &true);
+    }
+
+    /// Amount carved for voters the next time `fund_voter_rewards` runs.
+    pub fn set_reward_pool(env: Env, amount: i128) {
+        env.storage().instance().set(&MockKey::RewardPool, &amount);
+    }
+
+    /// Record a pool as already carved, as the market's own crank does.
+    pub fn set_carved(env: Env, poll_id: u64, amount: i128) {
+        env.storage().persistent().set(&MockKey::Carved(poll_id), &amount);
+    }
+
+    // ── PredictionMarketInterface ─────────────────────────────────────────────
+
+    pub fn has_user_staked(env: Env, poll_id: u64, user: Address) -> bool {
+        env.storage().persistent().has(&MockKey::Staked(poll_id, user))
+    }
+
+    pub fn resolve_poll(env: Env, poll_id: u64, outcome: bool) {
+        Self::resolve_poll_outcome(env, poll_id, if outcome { 0 } else { 1 });
+    }
+
+    pub fn resolve_poll_outcome(env: Env, poll_id: u64, outcome_index: u32) {
+        Self::settle(&env, poll_id, PollOutcome::Winner(outcome_index));
+    }
+
+    /// Voiding refunds every stake, so a carved pool is written off.
+    pub fn void_poll(env: Env, poll_id: u64, slash: bool) {
+        Self::settle(&env, poll_id, PollOutcome::Void);
+        env.storage().persistent().set(&MockKey::Slashed(poll_id), &slash);
+        env.storage().persistent().remove(&MockKey::Carved(poll_id));
+    }
+
+    pub fn escalate_poll(env: Env, poll_id: u64) {
+        let mut poll = Self::get_poll(env.clone(), poll_id);
+        poll.status = PollStatus::AdminReview;
+        Self::set_poll(env, poll);
+    }
+
+    pub fn mark_disputed(env: Env, poll_id: u64) {
+        let mut poll = Self::get_poll(env.clone(), poll_id);
+        poll.status = PollStatus::Disputed;
+        Self::set_poll(env, poll);
+    }
+
+    pub fn get_poll(env: Env, poll_id: u64) -> Poll {
+        env.storage()
+            .persistent()
+            .get(&MockKey::Poll(poll_id))
+            .unwrap_or_else(|| Poll {
+                poll_id,
+                match_id: 1,
+                creator: env.current_contract_address(),
+                question: String::from_str(&env, "Will Palmer score?"),
+                category: PollCategory::PlayerEvent,
+                lock_time: 0,
+                outcomes: vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")],
+                outcome_pools: vec![&env, 0, 0],
+                outcome_counts: vec![&env, 0, 0],
+                status: PollStatus::Voting,
+                outcome: PollOutcome::Pending,
+                resolution_time: 0,
+                created_at: 0,
+            })
+    }
+
+    pub fn get_token_address(env: Env) -> Address {
+        env.storage().instance().get(&MockKey::Token).unwrap()
+    }
+
+    pub fn get_treasury_address(env: Env) -> Address {
+        env.storage().instance().get(&MockKey::Treasury).unwrap()
+    }
+
+    pub fn fund_voter_rewards(env: Env, poll_id: u64) -> i128 {
+        let key = MockKey::Carved(poll_id);
+        if let Some(amount) = env.storage().persistent().get(&key) {
+            return amount;
+        }
+        let amount: i128 = env.storage().instance().get(&MockKey::RewardPool).unwrap_or(0);
+        if amount > 0 {
+            let oracle: Address = env.storage().instance().get(&MockKey::Oracle).unwrap();
+            token::Client::new(&env, &Self::get_token_address(env.clone()))
+                .transfer(&env.current_contract_address(), &oracle, &amount);
+        }
+        env.storage().persistent().set(&key, &amount);
+        amount
+    }
+
+    pub fn get_voter_reward_pool(env: Env, poll_id: u64) -> i128 {
+        env.storage().persistent().get(&MockKey::Carved(poll_id)).unwrap_or(0)
+    }
+
+    // ── Assertions ────────────────────────────────────────────────────────────
+
+    /// `Some(true)` if the poll was resolved to Yes, `Some(false)` for No.
+    pub fn resolved(env: Env, poll_id: u64) -> Option<bool> {
+        match Self::get_poll(env, poll_id).outcome {
+            PollOutcome::Winner(index) => Some(index == 0),
+            PollOutcome::Pending | PollOutcome::Void => None,
+        }
+    }
+
+    pub fn escalated(env: Env, poll_id: u64) -> bool {
+        Self::get_poll(env, poll_id).status == PollStatus::AdminReview
+    }
+
+    pub fn voided(env: Env, poll_id: u64) -> bool {
+        Self::get_poll(env, poll_id).outcome == PollOutcome::Void
+    }
+
+    pub fn slashed(env: Env, poll_id: u64) -> bool {
+        env.storage().persistent().get(&MockKey::Slashed(poll_id)).unwrap_or(false)
+    }
+}
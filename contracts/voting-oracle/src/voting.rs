@@ -0,0 +1,272 @@
+use soroban_sdk::{Address, Env, Symbol};
+use predictx_shared::{OracleError, VoteChoice, VoteTally, VOTING_WINDOW_SECS};
+use crate::{DataKey, get_market, market::PredictionMarketClient, rewards};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+//
+// Tallies and ballots live in persistent storage: voter rewards are paid
+// against them after the dispute window, and a dispute can run for longer
+// than any temporary-storage TTL.
+
+pub(crate) fn load_tally(env: &Env, poll_id: u64) -> Result<VoteTally, OracleError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoteTally(poll_id))
+        .ok_or(OracleError::VotingNotOpen)
+}
+
+pub(crate) fn save_tally(env: &Env, tally: &VoteTally) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VoteTally(tally.poll_id), tally);
+}
+
+fn has_voted(env: &Env, poll_id: u64, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::HasVoted(poll_id, voter.clone()))
+}
+
+// ── Voting ────────────────────────────────────────────────────────────────────
+
+/// Open the community voting window for a poll.
+///
+/// Only the configured `PredictionMarket` may open voting; it does so from its
+/// lifecycle crank once the poll's match has finished.
+pub fn start_voting(env: &Env, poll_id: u64) -> Result<VoteTally, OracleError> {
+    let market = get_market(env)?;
+    market.require_auth();
+
+    if env.storage().persistent().has(&DataKey::VoteTally(poll_id)) {
+        return Err(OracleError::VotingAlreadyStarted);
+    }
+
+    let voting_end_time = env.ledger().timestamp() + VOTING_WINDOW_SECS;
+    let tally = VoteTally {
+        poll_id,
+        yes_votes: 0,
+        no_votes: 0,
+        unclear_votes: 0,
+        total_voters: 0,
+        voting_end_time,
+        reward_pool: 0,
+    };
+    save_tally(env, &tally);
+
+    env.events().publish(
+        (Symbol::new(env, "VotingStarted"), poll_id),
+        voting_end_time,
+    );
+
+    Ok(tally)
+}
+
+/// Cast a community vote on a poll's outcome.
+///
+/// Voters must not have staked on the poll — checked against
+/// `PredictionMarket::has_user_staked` — and may vote once per poll.
+pub fn cast_vote(
+    env: &Env,
+    voter: Address,
+    poll_id: u64,
+    choice: VoteChoice,
+) -> Result<VoteTally, OracleError> {
+    voter.require_auth();
+
+    let mut tally = load_tally(env, poll_id)?;
+    if env.ledger().timestamp() >= tally.voting_end_time {
+        return Err(OracleError::VotingWindowExpired);
+    }
+    if has_voted(env, poll_id, &voter) {
+        return Err(OracleError::AlreadyVoted);
+    }
+
+    let market = PredictionMarketClient::new(env, &get_market(env)?);
+    if market.has_user_staked(&poll_id, &voter) {
+        return Err(OracleError::VoterIsStaker);
+    }
+
+    match choice {
+        VoteChoice::Yes => tally.yes_votes += 1,
+        VoteChoice::No => tally.no_votes += 1,
+        VoteChoice::Unclear => tally.unclear_votes += 1,
+    }
+    tally.total_voters += 1;
+    save_tally(env, &tally);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::HasVoted(poll_id, voter.clone()), &true);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Ballot(poll_id, voter.clone()), &choice);
+
+    let mut stats = rewards::load_user_stats(env, &voter);
+    stats.votes_cast += 1;
+    rewards::save_user_stats(env, &voter, &stats);
+
+    env.events().publish(
+        (Symbol::new(env, "VoteCast"), poll_id, voter),
+        choice,
+    );
+
+    Ok(tally)
+}
+
+// ── View functions ────────────────────────────────────────────────────────────
+
+/// Current vote tally and voting window for a poll.
+pub fn get_voting_stats(env: &Env, poll_id: u64) -> Result<VoteTally, OracleError> {
+    load_tally(env, poll_id)
+}
+
+/// Whether `user` could successfully call `cast_vote` on this poll right now.
+pub fn can_vote(env: &Env, poll_id: u64, user: &Address) -> bool {
+    let tally = match load_tally(env, poll_id) {
+        Ok(t) => t,
+        Err(_) => return false,
+    };
+    if env.ledger().timestamp() >= tally.voting_end_time || has_voted(env, poll_id, user) {
+        return false;
+    }
+    let market = match get_market(env) {
+        Ok(m) => PredictionMarketClient::new(env, &m),
+        Err(_) => return false,
+    };
+    !market.has_user_staked(&poll_id, user)
+}
+
+/// The choice `voter` made on a poll, if they voted.
+pub fn get_ballot(env: &Env, poll_id: u64, voter: &Address) -> Option<VoteChoice> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Ballot(poll_id, voter.clone()))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env,
+    };
+    use predictx_shared::{OracleError, VoteChoice, VOTING_WINDOW_SECS};
+    use crate::testutils::{MockMarket, MockMarketClient};
+    use crate::{VotingOracle, VotingOracleClient};
+
+    const START: u64 = 1_000_000;
+
+    fn setup() -> (Env, VotingOracleClient<'static>, MockMarketClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = START);
+
+        let market = MockMarket::register(&env);
+
+        let oracle_id = env.register(VotingOracle, ());
+        let oracle = VotingOracleClient::new(&env, &oracle_id);
+        oracle.initialize(&Address::generate(&env));
+        oracle.set_prediction_market(&market.address);
+
+        (env, oracle, market)
+    }
+
+    #[test]
+    fn start_voting_opens_window() {
+        let (_, oracle, _) = setup();
+        let tally = oracle.start_voting(&1_u64);
+        assert_eq!(tally.voting_end_time, START + VOTING_WINDOW_SECS);
+        assert_eq!(tally.total_voters, 0);
+        assert_eq!(oracle.get_voting_stats(&1_u64), tally);
+    }
+
+    #[test]
+    fn start_voting_twice_fails() {
+        let (_, oracle, _) = setup();
+        oracle.start_voting(&1_u64);
+        let err = oracle.try_start_voting(&1_u64).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::VotingAlreadyStarted);
+    }
+
+    #[test]
+    fn cast_vote_updates_tally() {
+        let (env, oracle, _) = setup();
+        oracle.start_voting(&1_u64);
+
+        oracle.cast_vote(&Address::generate(&env), &1_u64, &VoteChoice::Yes);
+        oracle.cast_vote(&Address::generate(&env), &1_u64, &VoteChoice::Yes);
+        oracle.cast_vote(&Address::generate(&env), &1_u64, &VoteChoice::No);
+        let voter = Address::generate(&env);
+        let tally = oracle.cast_vote(&voter, &1_u64, &VoteChoice::Unclear);
+
+        assert_eq!(tally.yes_votes, 2);
+        assert_eq!(tally.no_votes, 1);
+        assert_eq!(tally.unclear_votes, 1);
+        assert_eq!(tally.total_voters, 4);
+        assert_eq!(oracle.get_ballot(&1_u64, &voter), Some(VoteChoice::Unclear));
+    }
+
+    #[test]
+    fn cast_vote_rejects_double_vote() {
+        let (env, oracle, _) = setup();
+        oracle.start_voting(&1_u64);
+        let voter = Address::generate(&env);
+        oracle.cast_vote(&voter, &1_u64, &VoteChoice::Yes);
+
+        let err = oracle.try_cast_vote(&voter, &1_u64, &VoteChoice::No).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::AlreadyVoted);
+    }
+
+    #[test]
+    fn cast_vote_rejects_staker() {
+        let (env, oracle, market) = setup();
+        oracle.start_voting(&1_u64);
+        let staker = Address::generate(&env);
+        market.set_staked(&1_u64, &staker);
+
+        let err = oracle.try_cast_vote(&staker, &1_u64, &VoteChoice::Yes).unwrap_err().unwrap();
+        assert_eq!(err, OracleError::VoterIsStaker);
+    }
+
+    #[test]
+    fn cast_vote_rejects_before_voting_opens() {
+        let (env, oracle, _) = setup();
+        let err = oracle
+            .try_cast_vote(&Address::generate(&env), &1_u64, &VoteChoice::Yes)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::VotingNotOpen);
+    }
+
+    #[test]
+    fn cast_vote_rejects_after_window() {
+        let (env, oracle, _) = setup();
+        oracle.start_voting(&1_u64);
+        env.ledger().with_mut(|l| l.timestamp = START + VOTING_WINDOW_SECS);
+
+        let err = oracle
+            .try_cast_vote(&Address::generate(&env), &1_u64, &VoteChoice::Yes)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, OracleError::VotingWindowExpired);
+    }
+
+    #[test]
+    fn can_vote_reflects_eligibility() {
+        let (env, oracle, market) = setup();
+        let voter = Address::generate(&env);
+        let staker = Address::generate(&env);
+        market.set_staked(&1_u64, &staker);
+        assert!(!oracle.can_vote(&1_u64, &voter));
+
+        oracle.start_voting(&1_u64);
+        assert!(oracle.can_vote(&1_u64, &voter));
+        assert!(!oracle.can_vote(&1_u64, &staker));
+
+        oracle.cast_vote(&voter, &1_u64, &VoteChoice::No);
+        assert!(!oracle.can_vote(&1_u64, &voter));
+    }
+}
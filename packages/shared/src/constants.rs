@@ -1,6 +1,9 @@
 /// Platform fee in basis points (BPS). `500` = 5%.
 pub const PLATFORM_FEE_BPS: u32 = 500;
 
+/// Default fee for withdrawing a stake before lock, in BPS. `200` = 2%.
+pub const DEFAULT_EXIT_FEE_BPS: u32 = 200;
+
 /// Maximum voter reward in basis points. `100` = 1%.
 pub const VOTER_REWARD_BPS: u32 = 100;
 
@@ -10,6 +13,9 @@ pub const VOTING_WINDOW_SECS: u64 = 7_200;
 /// Duration of the dispute window in seconds. `86_400` = 24 hours.
 pub const DISPUTE_WINDOW_SECS: u64 = 86_400;
 
+/// Default fee escrowed to open a dispute, in token base units. `1_000_000_000` = 100 tokens.
+pub const DISPUTE_FEE_AMOUNT: i128 = 1_000_000_000;
+
 /// Vote share threshold for automatic resolution in BPS. `8_500` = 85%.
 pub const AUTO_RESOLVE_THRESHOLD_BPS: u32 = 8_500;
 
@@ -22,9 +28,25 @@ pub const MULTI_SIG_REQUIRED: u32 = 3;
 /// Maximum length (in characters) for a poll question.
 pub const MAX_QUESTION_LENGTH: u32 = 256;
 
+/// Most entries a listing view returns per call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Most ids a filtered listing view looks at per call, matching or not.
+pub const MAX_PAGE_SCAN: u32 = 200;
+
+/// Latest a poll may lock after its match kicks off, for in-play polls, in
+/// seconds. `5_400` = 90 minutes.
+pub const MAX_LOCK_AFTER_KICKOFF_SECS: u64 = 5_400;
+
+/// Maximum number of outcomes a categorical poll can offer.
+pub const MAX_POLL_OUTCOMES: u32 = 32;
+
 /// Maximum number of polls that can be attached to a single match.
 pub const MAX_POLLS_PER_MATCH: u32 = 50;
 
+/// Maximum number of live events that can be logged for a single match.
+pub const MAX_EVENTS_PER_MATCH: u32 = 200;
+
 /// Basis points denominator. Used as: `amount * fee_bps / BPS_DENOMINATOR`.
 pub const BPS_DENOMINATOR: u32 = 10_000;
 
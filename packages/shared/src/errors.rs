@@ -1,6 +1,7 @@
 use soroban_sdk::contracterror;
 
-/// All errors that can be returned by PredictX contracts.
+/// Errors returned by the PredictX contracts other than the VotingOracle,
+/// which has its own `OracleError`.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum PredictXError {
@@ -24,7 +25,8 @@ pub enum PredictXError {
     InsufficientBalance = 9,
     /// Stake amount must be greater than zero.
     StakeAmountZero = 10,
-    /// Caller has already placed a stake on this poll.
+    /// Caller has already placed a stake on this poll, or the poll already has
+    /// stakes and can no longer be edited.
     AlreadyStaked = 11,
     /// Caller has not staked on this poll.
     NotStaker = 12,
@@ -72,4 +74,97 @@ pub enum PredictXError {
     ContractPaused = 33,
     /// Stake amount is below the minimum required.
     StakeBelowMinimum = 34,
+    /// Poll has not been resolved yet — claims are not open.
+    PollNotResolved = 35,
+    /// Claims are frozen until the dispute window closes.
+    DisputeWindowOpen = 39,
+    /// Match has been cancelled.
+    MatchCancelled = 43,
+    /// Match has already finished.
+    MatchAlreadyFinished = 44,
+    /// Poll is not cancelled, so there is nothing to refund.
+    PollNotCancelled = 45,
+    /// Requested match status change is not allowed from the current status.
+    InvalidMatchTransition = 46,
+    /// Match has not reached its kickoff time.
+    MatchNotStarted = 47,
+    /// Half-time score exceeds the final score.
+    InvalidScore = 48,
+    /// Match already has `MAX_EVENTS_PER_MATCH` logged events.
+    MaxMatchEventsReached = 49,
+    /// Fee in basis points exceeds `BPS_DENOMINATOR`.
+    InvalidFeeBps = 50,
+    /// Stake is above the poll's maximum single stake.
+    StakeAboveMaximum = 51,
+    /// Stake would take the user's total on the poll over the per-user cap.
+    UserPollCapExceeded = 52,
+    /// Stake would take the user's total on the match over the per-user cap.
+    UserMatchCapExceeded = 53,
+    /// Stake would take the poll's pool over the pool cap.
+    PollPoolCapExceeded = 54,
+    /// A configured stake limit or creator bond is negative or inconsistent.
+    InvalidConfigAmount = 55,
+    /// Projected payout after the stake is below the staker's `min_payout`.
+    PayoutBelowMinimum = 56,
+}
+
+/// Errors returned by the VotingOracle contract.
+///
+/// Codes share one space with `PredictXError`: a code the two enums have in
+/// common means the same thing, and oracle-only codes are never reused by the
+/// market. The oracle keeps its own enum because a contract error type is
+/// capped at 50 variants.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OracleError {
+    /// Contract has not been initialised yet.
+    NotInitialized = 1,
+    /// Contract has already been initialised.
+    AlreadyInitialized = 2,
+    /// Caller is not the admin.
+    Unauthorized = 3,
+    /// Poll outcome has already been resolved.
+    PollAlreadyResolved = 8,
+    /// Stake amount must be greater than zero.
+    StakeAmountZero = 10,
+    /// Reward has already been claimed.
+    AlreadyClaimed = 13,
+    /// Caller did not stake on the winning side.
+    NotOnWinningSide = 14,
+    /// Voting window is not open.
+    VotingNotOpen = 15,
+    /// Caller has already cast a vote.
+    AlreadyVoted = 16,
+    /// Stakers cannot vote on their own poll.
+    VoterIsStaker = 17,
+    /// Voting window has expired.
+    VotingWindowExpired = 18,
+    /// A dispute is already open for this poll.
+    DisputeAlreadyOpen = 19,
+    /// Dispute fee was not provided.
+    DisputeFeeRequired = 20,
+    /// Outcome value is not valid for this poll.
+    InvalidOutcome = 27,
+    /// Community vote did not reach consensus threshold.
+    ConsensusNotReached = 28,
+    /// Admin address is already registered.
+    AdminAlreadyRegistered = 29,
+    /// Not enough admin approvals for this action.
+    InsufficientAdminApprovals = 30,
+    /// Poll has not been resolved yet — claims are not open.
+    PollNotResolved = 35,
+    /// Voting has already been opened for this poll.
+    VotingAlreadyStarted = 36,
+    /// Voting window has not closed yet.
+    VotingStillOpen = 37,
+    /// The dispute window for this poll has closed.
+    DisputeWindowClosed = 38,
+    /// Claims are frozen until the dispute window closes.
+    DisputeWindowOpen = 39,
+    /// This admin has already approved the pending action.
+    AlreadyApproved = 40,
+    /// No dispute exists for this poll.
+    DisputeNotFound = 41,
+    /// Caller did not vote on this poll.
+    NotVoter = 42,
 }
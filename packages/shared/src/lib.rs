@@ -3,11 +3,15 @@
 pub mod constants;
 pub mod errors;
 pub mod storage;
+pub mod treasury;
 pub mod types;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 
 pub use constants::*;
-pub use errors::PredictXError;
+pub use errors::{OracleError, PredictXError};
 pub use storage::DataKey;
+pub use treasury::{pay_treasury, TreasuryClient};
 pub use types::*;
 
 #[cfg(test)]
@@ -40,9 +44,93 @@ mod test {
         assert_eq!(PredictXError::TransferFailed as u32, 32);
     }
 
+    #[test]
+    fn error_codes_are_stable() {
+        // Discriminants are part of the ABI; never renumber an existing code
+        assert_eq!(PredictXError::NotInitialized as u32, 1);
+        assert_eq!(PredictXError::AlreadyInitialized as u32, 2);
+        assert_eq!(PredictXError::Unauthorized as u32, 3);
+        assert_eq!(PredictXError::PollNotFound as u32, 4);
+        assert_eq!(PredictXError::PollNotActive as u32, 5);
+        assert_eq!(PredictXError::PollLocked as u32, 6);
+        assert_eq!(PredictXError::PollNotLocked as u32, 7);
+        assert_eq!(PredictXError::PollAlreadyResolved as u32, 8);
+        assert_eq!(PredictXError::InsufficientBalance as u32, 9);
+        assert_eq!(PredictXError::StakeAmountZero as u32, 10);
+        assert_eq!(PredictXError::AlreadyStaked as u32, 11);
+        assert_eq!(PredictXError::NotStaker as u32, 12);
+        assert_eq!(PredictXError::AlreadyClaimed as u32, 13);
+        assert_eq!(PredictXError::NotOnWinningSide as u32, 14);
+        assert_eq!(PredictXError::VotingNotOpen as u32, 15);
+        assert_eq!(PredictXError::AlreadyVoted as u32, 16);
+        assert_eq!(PredictXError::VoterIsStaker as u32, 17);
+        assert_eq!(PredictXError::VotingWindowExpired as u32, 18);
+        assert_eq!(PredictXError::DisputeAlreadyOpen as u32, 19);
+        assert_eq!(PredictXError::DisputeFeeRequired as u32, 20);
+        assert_eq!(PredictXError::InvalidPollCategory as u32, 21);
+        assert_eq!(PredictXError::InvalidLockTime as u32, 22);
+        assert_eq!(PredictXError::MatchNotFound as u32, 23);
+        assert_eq!(PredictXError::MatchAlreadyStarted as u32, 24);
+        assert_eq!(PredictXError::PollQuestionTooLong as u32, 25);
+        assert_eq!(PredictXError::MaxPollsPerMatchReached as u32, 26);
+        assert_eq!(PredictXError::InvalidOutcome as u32, 27);
+        assert_eq!(PredictXError::ConsensusNotReached as u32, 28);
+        assert_eq!(PredictXError::AdminAlreadyRegistered as u32, 29);
+        assert_eq!(PredictXError::InsufficientAdminApprovals as u32, 30);
+        assert_eq!(PredictXError::EmergencyWithdrawNotAllowed as u32, 31);
+        assert_eq!(PredictXError::TransferFailed as u32, 32);
+        assert_eq!(PredictXError::ContractPaused as u32, 33);
+        assert_eq!(PredictXError::StakeBelowMinimum as u32, 34);
+        assert_eq!(PredictXError::PollNotResolved as u32, 35);
+        assert_eq!(PredictXError::DisputeWindowOpen as u32, 39);
+        assert_eq!(PredictXError::MatchCancelled as u32, 43);
+        assert_eq!(PredictXError::MatchAlreadyFinished as u32, 44);
+        assert_eq!(PredictXError::PollNotCancelled as u32, 45);
+        assert_eq!(PredictXError::InvalidMatchTransition as u32, 46);
+        assert_eq!(PredictXError::MatchNotStarted as u32, 47);
+        assert_eq!(PredictXError::InvalidScore as u32, 48);
+        assert_eq!(PredictXError::MaxMatchEventsReached as u32, 49);
+        assert_eq!(PredictXError::InvalidFeeBps as u32, 50);
+        assert_eq!(PredictXError::StakeAboveMaximum as u32, 51);
+        assert_eq!(PredictXError::UserPollCapExceeded as u32, 52);
+        assert_eq!(PredictXError::UserMatchCapExceeded as u32, 53);
+        assert_eq!(PredictXError::PollPoolCapExceeded as u32, 54);
+        assert_eq!(PredictXError::InvalidConfigAmount as u32, 55);
+        assert_eq!(PredictXError::PayoutBelowMinimum as u32, 56);
+    }
+
+    #[test]
+    fn oracle_error_codes_are_stable() {
+        assert_eq!(OracleError::NotInitialized as u32, 1);
+        assert_eq!(OracleError::AlreadyInitialized as u32, 2);
+        assert_eq!(OracleError::Unauthorized as u32, 3);
+        assert_eq!(OracleError::PollAlreadyResolved as u32, 8);
+        assert_eq!(OracleError::StakeAmountZero as u32, 10);
+        assert_eq!(OracleError::AlreadyClaimed as u32, 13);
+        assert_eq!(OracleError::NotOnWinningSide as u32, 14);
+        assert_eq!(OracleError::VotingNotOpen as u32, 15);
+        assert_eq!(OracleError::AlreadyVoted as u32, 16);
+        assert_eq!(OracleError::VoterIsStaker as u32, 17);
+        assert_eq!(OracleError::VotingWindowExpired as u32, 18);
+        assert_eq!(OracleError::DisputeAlreadyOpen as u32, 19);
+        assert_eq!(OracleError::DisputeFeeRequired as u32, 20);
+        assert_eq!(OracleError::InvalidOutcome as u32, 27);
+        assert_eq!(OracleError::ConsensusNotReached as u32, 28);
+        assert_eq!(OracleError::AdminAlreadyRegistered as u32, 29);
+        assert_eq!(OracleError::InsufficientAdminApprovals as u32, 30);
+        assert_eq!(OracleError::PollNotResolved as u32, 35);
+        assert_eq!(OracleError::VotingAlreadyStarted as u32, 36);
+        assert_eq!(OracleError::VotingStillOpen as u32, 37);
+        assert_eq!(OracleError::DisputeWindowClosed as u32, 38);
+        assert_eq!(OracleError::DisputeWindowOpen as u32, 39);
+        assert_eq!(OracleError::AlreadyApproved as u32, 40);
+        assert_eq!(OracleError::DisputeNotFound as u32, 41);
+        assert_eq!(OracleError::NotVoter as u32, 42);
+    }
+
     #[test]
     fn poll_status_discriminants_are_stable() {
         assert_eq!(PollStatus::Active as u32, 0);
         assert_eq!(PollStatus::Cancelled as u32, 6);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,39 @@
+//! Test doubles shared by the contracts' unit tests. Only built for tests or
+//! with the `testutils` feature.
+
+use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+use crate::FeeSource;
+
+/// Stand-in for the `Treasury` contract: pulls deposits like the real one and
+/// keeps a per-source tally.
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn set_token(env: Env, token: Address) {
+        env.storage().instance().set(&symbol_short!("token"), &token);
+    }
+
+    pub fn deposit_fees(env: Env, from: Address, amount: i128, source: FeeSource) -> i128 {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&symbol_short!("token")).unwrap();
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        let total: i128 = env.storage().instance().get(&source).unwrap_or(0) + amount;
+        env.storage().instance().set(&source, &total);
+        total
+    }
+
+    pub fn fees_collected(env: Env, source: FeeSource) -> i128 {
+        env.storage().instance().get(&source).unwrap_or(0)
+    }
+}
+
+impl MockTreasury {
+    /// Register a `MockTreasury` that accepts `token`.
+    pub fn register<'a>(env: &Env, token: &Address) -> MockTreasuryClient<'a> {
+        let treasury = MockTreasuryClient::new(env, &env.register(MockTreasury, ()));
+        treasury.set_token(token);
+        treasury
+    }
+}
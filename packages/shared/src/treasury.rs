@@ -0,0 +1,32 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contractclient, vec, Address, Env, IntoVal, Symbol,
+};
+use crate::FeeSource;
+
+/// Subset of the `Treasury` contract interface used by fee-paying contracts.
+#[contractclient(name = "TreasuryClient")]
+pub trait TreasuryInterface {
+    fn deposit_fees(env: Env, from: Address, amount: i128, source: FeeSource) -> i128;
+}
+
+/// Pay `amount` of `token` from the current contract into the treasury,
+/// booked under `source`.
+///
+/// The treasury pulls the tokens itself with `token.transfer`, so that nested
+/// call is pre-authorised on behalf of the current contract here.
+pub fn pay_treasury(env: &Env, token: &Address, treasury: &Address, amount: i128, source: FeeSource) {
+    let from = env.current_contract_address();
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (from.clone(), treasury.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    TreasuryClient::new(env, treasury).deposit_fees(&from, &amount, &source);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
 // ── Enums ─────────────────────────────────────────────────────────────────────
 
@@ -22,6 +22,26 @@ pub enum PollStatus {
     Cancelled = 6,
 }
 
+/// Lifecycle state of a match.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchStatus {
+    /// Created, waiting for kickoff.
+    Scheduled = 0,
+    /// In play.
+    Live = 1,
+    /// Half-time break.
+    HalfTime = 2,
+    /// Full-time — polls may open community voting.
+    Finished = 3,
+    /// Moved to a later kickoff.
+    Postponed = 4,
+    /// Kicked off but could not be completed — polls are cancelled.
+    Abandoned = 5,
+    /// Called off before kickoff — polls are cancelled.
+    Cancelled = 6,
+}
+
 /// Category of prediction a poll covers.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -36,7 +56,76 @@ pub enum PollCategory {
     Other = 3,
 }
 
-/// Which side of a poll a user staked on.
+/// One side of a match.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Team {
+    Home = 0,
+    Away = 1,
+}
+
+/// Kind of in-play incident recorded in a match's event log.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchEventKind {
+    Goal = 0,
+    /// Credited to the opposing side's score.
+    OwnGoal = 1,
+    YellowCard = 2,
+    RedCard = 3,
+    /// A penalty that was converted; missed penalties are not logged.
+    Penalty = 4,
+    Substitution = 5,
+}
+
+/// Machine-checkable prediction a poll can carry next to its display question.
+///
+/// Goal lines are half-goal lines so there is never a push:
+/// `TotalGoalsOver(2)` reads "over 2.5 goals", `TotalGoalsUnder(2)` "under 2.5".
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PollCondition {
+    /// `ScorePrediction`.
+    TotalGoalsOver(u32),
+    /// `ScorePrediction`.
+    TotalGoalsUnder(u32),
+    /// `TeamEvent` or `ScorePrediction`.
+    TeamWins(Team),
+    /// `ScorePrediction`.
+    BothTeamsScore,
+    /// `ScorePrediction`.
+    ExactScore(Score),
+    /// `PlayerEvent`: the player with this feed id scores.
+    PlayerScores(u32),
+    /// `TeamEvent`: the team concedes no goals.
+    CleanSheet(Team),
+    /// `PlayerEvent`: the player with this feed id is shown a yellow or red card.
+    PlayerBooked(u32),
+    /// `TeamEvent`: one of the team's players is sent off.
+    TeamRedCard(Team),
+    /// Three-outcome poll settled as home win / draw / away win (indices 0, 1, 2).
+    MatchResult,
+}
+
+/// Where a treasury deposit came from, for per-source fee accounting.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeSource {
+    /// Platform fee taken from winning payouts.
+    PlatformFee = 0,
+    /// Escrowed fee of a dispute that was rejected.
+    DisputeFee = 1,
+    /// Creator bond forfeited on an invalid poll.
+    SlashedBond = 2,
+    /// Voter reward pool left with no voter to claim it after a dispute
+    /// changed the outcome.
+    UnearnedVoterRewards = 3,
+    /// Fee kept when a stake is withdrawn before lock.
+    ExitFee = 4,
+}
+
+/// Which side of a binary poll a user staked on. The discriminant is the
+/// outcome index.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum StakeSide {
@@ -44,6 +133,19 @@ pub enum StakeSide {
     No = 1,
 }
 
+/// How a poll was settled.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PollOutcome {
+    /// Not resolved yet.
+    Pending,
+    /// Index of the winning outcome.
+    Winner(u32),
+    /// No winner (e.g. the player did not start) — every stake is refunded
+    /// in full with no fee.
+    Void,
+}
+
 /// A community voter's choice.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -73,8 +175,44 @@ pub struct Match {
     pub kickoff_time: u64,
     /// Admin who created the match.
     pub created_by: Address,
-    /// Set to `true` after the match ends; prerequisite for poll voting.
-    pub is_finished: bool,
+    pub status: MatchStatus,
+    /// Full-time goals, recorded by `finish_match`.
+    pub home_score: Option<u32>,
+    pub away_score: Option<u32>,
+    /// Half-time goals, if the admin supplied them at full-time.
+    pub half_time_home_score: Option<u32>,
+    pub half_time_away_score: Option<u32>,
+    /// Every status change, oldest first, starting with `Scheduled` at creation.
+    pub transitions: Vec<MatchTransition>,
+}
+
+/// Goals scored by each side.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Score {
+    pub home: u32,
+    pub away: u32,
+}
+
+/// One entry in a match's live event log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchEvent {
+    /// Match minute the incident happened in.
+    pub minute: u32,
+    pub kind: MatchEventKind,
+    /// Side of the player involved; for an own goal, the side that conceded.
+    pub team: Team,
+    /// Data-feed id of the player involved (the player coming on for a substitution).
+    pub player_id: u32,
+}
+
+/// A match status change and the ledger time it happened.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchTransition {
+    pub status: MatchStatus,
+    pub at: u64,
 }
 
 /// A prediction market poll belonging to a match.
@@ -89,17 +227,16 @@ pub struct Poll {
     pub category: PollCategory,
     /// Unix timestamp after which staking is disallowed.
     pub lock_time: u64,
-    /// Total token amount staked on Yes (i128 — Soroban token standard).
-    pub yes_pool: i128,
-    /// Total token amount staked on No.
-    pub no_pool: i128,
-    /// Number of individual stakers on Yes.
-    pub yes_count: u32,
-    /// Number of individual stakers on No.
-    pub no_count: u32,
+    /// Outcome labels. Binary polls are `["Yes", "No"]`, so index 0 is Yes
+    /// and index 1 is No.
+    pub outcomes: Vec<String>,
+    /// Total token amount staked on each outcome (i128 — Soroban token standard).
+    pub outcome_pools: Vec<i128>,
+    /// Number of individual stakers on each outcome.
+    pub outcome_counts: Vec<u32>,
     pub status: PollStatus,
-    /// `None` until resolved; `Some(true)` = Yes won, `Some(false)` = No won.
-    pub outcome: Option<bool>,
+    /// `Pending` until resolved.
+    pub outcome: PollOutcome,
     /// Unix timestamp when the poll was resolved.
     pub resolution_time: u64,
     /// Unix timestamp when the poll was created.
@@ -114,12 +251,24 @@ pub struct Stake {
     pub poll_id: u64,
     /// Token amount staked.
     pub amount: i128,
-    pub side: StakeSide,
+    /// Index of the outcome staked on; for binary polls see `StakeSide`.
+    pub outcome: u32,
     /// Whether the user has claimed their reward.
     pub claimed: bool,
+    /// Time of the first stake; top-ups are recorded as `StakeEntry`s.
     pub staked_at: u64,
 }
 
+/// One stake placement — the opening stake or a later top-up — in a user's
+/// history on a poll. Withdrawals are recorded with a negative `amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeEntry {
+    pub outcome: u32,
+    pub amount: i128,
+    pub at: u64,
+}
+
 /// Aggregated community vote data for a poll.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -143,6 +292,12 @@ pub struct Dispute {
     /// IPFS hash or external evidence reference.
     pub evidence_hash: String,
     pub dispute_fee: i128,
+    /// Outcome the poll was resolved to when the dispute was raised.
+    pub original_outcome: PollOutcome,
+    /// Outcome the admins settled on; `Pending` until `required_approvals` of
+    /// them agree.
+    pub proposed_outcome: PollOutcome,
+    /// Admins who have approved an outcome so far, whichever they chose.
     pub admin_approvals: u32,
     /// Multi-sig threshold (default: 3).
     pub required_approvals: u32,